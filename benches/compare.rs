@@ -28,7 +28,7 @@ fn alloc_bumpalo<T: Default>() {
 
 fn alloc_bump_local<T: Default>() {
     let bump = Bump::builder()
-        .bump_capacity(ALLOCATIONS * std::mem::size_of::<T>())
+        .bump_capacity_for::<T>(ALLOCATIONS)
         .build();
 
     for _ in 0..ALLOCATIONS {
@@ -38,6 +38,21 @@ fn alloc_bump_local<T: Default>() {
     }
 }
 
+// Steady state: the allocator is created up front and `local()` skips the init check.
+fn alloc_bump_local_steady<T: Default>() {
+    let bump = Bump::builder()
+        .bump_capacity_for::<T>(ALLOCATIONS)
+        .build();
+    bump.prewarm_current();
+
+    for _ in 0..ALLOCATIONS {
+        // SAFETY: The allocator was created by `prewarm_current` and isn't reset.
+        let local = black_box(unsafe { bump.local_initialized_unchecked() });
+        let val: &mut T = local.as_inner().alloc(black_box(Default::default()));
+        black_box(val);
+    }
+}
+
 // Arc<Mutex<Bump>> approach.
 //
 // Even in single-threaded benchmarks, mutex lock/unlock adds ~4.7ns overhead per allocation
@@ -83,6 +98,20 @@ fn bump_local_big(b: &mut Bencher) {
     });
 }
 
+#[bench]
+fn bump_local_small_steady(b: &mut Bencher) {
+    b.iter(|| {
+        alloc_bump_local_steady::<Small>();
+    });
+}
+
+#[bench]
+fn bump_local_big_steady(b: &mut Bencher) {
+    b.iter(|| {
+        alloc_bump_local_steady::<Big>();
+    });
+}
+
 #[bench]
 fn mutex_bump_small(b: &mut Bencher) {
     b.iter(|| {
@@ -96,3 +125,120 @@ fn mutex_bump_big(b: &mut Bencher) {
         alloc_mutex_bump::<Big>();
     });
 }
+
+// First `local()` call on a fresh allocator, which creates the thread's allocator.
+//
+// Later calls don't touch the liveness flag, so only this path differs
+// between the two configurations.
+fn init_bump_local(build: impl Fn() -> Bump) {
+    let bump = build();
+    black_box(black_box(&bump).local());
+}
+
+#[bench]
+fn bump_local_init(b: &mut Bencher) {
+    b.iter(|| {
+        init_bump_local(|| Bump::builder().bump_capacity(64).build());
+    });
+}
+
+#[bench]
+fn bump_local_init_untracked(b: &mut Bencher) {
+    b.iter(|| {
+        init_bump_local(|| {
+            Bump::builder()
+                .bump_capacity(64)
+                .disable_liveness_tracking()
+                .build()
+        });
+    });
+}
+
+// `local()` lookups on a single allocator hit the per-thread cache of the last used allocator,
+// alternating between two allocators always goes through the `ThreadLocal` lookup.
+const LOOKUPS: usize = 10_000;
+
+#[bench]
+fn bump_local_lookup(b: &mut Bencher) {
+    let bump = Bump::new();
+    b.iter(|| {
+        for _ in 0..LOOKUPS {
+            black_box(black_box(&bump).local());
+        }
+    });
+}
+
+#[bench]
+fn bump_local_lookup_alternating(b: &mut Bencher) {
+    let bumps = [Bump::new(), Bump::new()];
+    b.iter(|| {
+        for i in 0..LOOKUPS {
+            black_box(black_box(&bumps[i % 2]).local());
+        }
+    });
+}
+
+fn alloc_big_bump_local(with: bool) {
+    let bump = Bump::builder()
+        .bump_capacity_for::<Big>(ALLOCATIONS)
+        .build();
+
+    for _ in 0..ALLOCATIONS {
+        let bump = black_box(&bump);
+        let val = if with {
+            bump.alloc_with(|| Big([black_box(1); 32]))
+        } else {
+            bump.alloc(Big([black_box(1); 32]))
+        };
+        black_box(val);
+    }
+}
+
+#[bench]
+fn bump_local_alloc_big(b: &mut Bencher) {
+    b.iter(|| {
+        alloc_big_bump_local(false);
+    });
+}
+
+#[bench]
+fn bump_local_alloc_with_big(b: &mut Bencher) {
+    b.iter(|| {
+        alloc_big_bump_local(true);
+    });
+}
+
+// Allocating all `Big` values at once resolves `local()` and bumps the pointer a single time.
+#[bench]
+fn bump_local_big_batched(b: &mut Bencher) {
+    b.iter(|| {
+        let bump = Bump::builder()
+            .bump_capacity_for::<Big>(ALLOCATIONS)
+            .build();
+
+        let vals = black_box(&bump).alloc_slice_fill_default::<Big>(ALLOCATIONS);
+        black_box(vals);
+    });
+}
+
+// Filling a 1 MiB byte slice with `memset` compared to writing each byte.
+const FILL_BYTES: usize = 1 << 20;
+
+#[bench]
+fn bump_local_fill_copy_bytes(b: &mut Bencher) {
+    let mut bump = Bump::builder().bump_capacity(FILL_BYTES).build();
+    b.iter(|| {
+        black_box(bump.alloc_slice_fill_copy(FILL_BYTES, black_box(0xAB_u8)));
+        bump.reset_all().unwrap();
+    });
+}
+
+#[bench]
+fn bump_local_fill_from_exact_bytes(b: &mut Bencher) {
+    let mut bump = Bump::builder().bump_capacity(FILL_BYTES).build();
+    b.iter(|| {
+        let value = black_box(0xAB_u8);
+        black_box(bump.alloc_slice_from_exact((0..FILL_BYTES).map(|_| value)));
+        bump.reset_all().unwrap();
+    });
+}
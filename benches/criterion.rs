@@ -0,0 +1,126 @@
+use std::{
+    hint::black_box,
+    sync::{Arc, Mutex},
+    thread,
+};
+
+use bump_local::Bump;
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+
+#[allow(dead_code)]
+#[derive(Default)]
+struct Small(u8);
+
+#[allow(dead_code)]
+#[derive(Default)]
+struct Big([usize; 32]);
+
+const ALLOCATIONS: usize = 10_000;
+
+fn alloc_bumpalo<T: Default>() {
+    let bump = bumpalo::Bump::with_capacity(ALLOCATIONS * std::mem::size_of::<T>());
+    for _ in 0..ALLOCATIONS {
+        let arena = black_box(&bump);
+        let val: &mut T = arena.alloc(black_box(Default::default()));
+        black_box(val);
+    }
+}
+
+fn alloc_bump_local<T: Default>() {
+    let bump = Bump::builder()
+        .bump_capacity_for::<T>(ALLOCATIONS)
+        .build();
+
+    for _ in 0..ALLOCATIONS {
+        let local = black_box(bump.local());
+        let val: &mut T = local.as_inner().alloc(black_box(Default::default()));
+        black_box(val);
+    }
+}
+
+fn alloc_mutex_bump<T: Default>() {
+    let bump = Arc::new(Mutex::new(bumpalo::Bump::with_capacity(
+        ALLOCATIONS * std::mem::size_of::<T>(),
+    )));
+
+    for _ in 0..ALLOCATIONS {
+        let guard = black_box(bump.lock().unwrap());
+        let val: &mut T = guard.alloc(black_box(Default::default()));
+        black_box(val);
+    }
+}
+
+fn single_threaded(c: &mut Criterion) {
+    let mut group = c.benchmark_group("single_threaded");
+    group.bench_function("bumpalo_small", |b| b.iter(alloc_bumpalo::<Small>));
+    group.bench_function("bumpalo_big", |b| b.iter(alloc_bumpalo::<Big>));
+    group.bench_function("bump_local_small", |b| b.iter(alloc_bump_local::<Small>));
+    group.bench_function("bump_local_big", |b| b.iter(alloc_bump_local::<Big>));
+    group.bench_function("mutex_bump_small", |b| b.iter(alloc_mutex_bump::<Small>));
+    group.bench_function("mutex_bump_big", |b| b.iter(alloc_mutex_bump::<Big>));
+    group.finish();
+}
+
+// Every thread allocates `ALLOCATIONS` values at the same time, into a shared `Bump`
+// or a shared mutex-protected bumpalo, which contend on the lock.
+//
+// Both include spawning the threads, so only the difference between them is meaningful.
+fn contended_bump_local(bump: &mut Bump, threads: usize) {
+    thread::scope(|s| {
+        for _ in 0..threads {
+            s.spawn(|| {
+                for _ in 0..ALLOCATIONS {
+                    let val = black_box(&*bump).alloc(black_box(Small::default()));
+                    black_box(val);
+                }
+            });
+        }
+    });
+    bump.reset_all().unwrap();
+}
+
+fn contended_mutex_bump(bump: &Mutex<bumpalo::Bump>, threads: usize) {
+    thread::scope(|s| {
+        for _ in 0..threads {
+            s.spawn(|| {
+                for _ in 0..ALLOCATIONS {
+                    let guard = black_box(bump.lock().unwrap());
+                    let val: &mut Small = guard.alloc(black_box(Small::default()));
+                    black_box(val);
+                }
+            });
+        }
+    });
+    bump.lock().unwrap().reset();
+}
+
+fn multi_threaded(c: &mut Criterion) {
+    let mut group = c.benchmark_group("multi_threaded");
+    for threads in [2, 4, 8] {
+        group.bench_with_input(
+            BenchmarkId::new("bump_local", threads),
+            &threads,
+            |b, &threads| {
+                let mut bump = Bump::builder()
+                    .bump_capacity_for::<Small>(ALLOCATIONS)
+                    .retain_dead_arenas(true)
+                    .build();
+                b.iter(|| contended_bump_local(&mut bump, threads));
+            },
+        );
+        group.bench_with_input(
+            BenchmarkId::new("mutex_bump", threads),
+            &threads,
+            |b, &threads| {
+                let bump = Mutex::new(bumpalo::Bump::with_capacity(
+                    threads * ALLOCATIONS * std::mem::size_of::<Small>(),
+                ));
+                b.iter(|| contended_mutex_bump(&bump, threads));
+            },
+        );
+    }
+    group.finish();
+}
+
+criterion_group!(benches, single_threaded, multi_threaded);
+criterion_main!(benches);
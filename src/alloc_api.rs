@@ -1,5 +1,5 @@
 use alloc::alloc::Layout;
-use core::ptr::NonNull;
+use core::{ptr::NonNull, sync::atomic::Ordering};
 
 #[cfg(feature = "allocator_api")]
 pub use core::alloc::{AllocError, Allocator};
@@ -12,13 +12,28 @@ use crate::Bump;
 unsafe impl Allocator for Bump {
     #[inline]
     fn allocate(&self, layout: Layout) -> Result<NonNull<[u8]>, AllocError> {
-        self.local().as_inner().allocate(layout)
+        let local = self.local();
+        let layout = local.adjust_layout(layout)?;
+        let ptr = match (local.as_inner().allocate(layout), local.overflow()) {
+            (Ok(ptr), _) => ptr,
+            (Err(_), Some(overflow)) => overflow.allocate(layout)?,
+            (Err(err), None) => return Err(err),
+        };
+        local.record_alloc();
+        self.count_allocation();
+        Ok(debug_check(ptr, layout))
     }
 
     #[inline]
     unsafe fn deallocate(&self, ptr: NonNull<u8>, layout: Layout) {
-        unsafe {
-            self.local().as_inner().deallocate(ptr, layout);
+        let local = self.local();
+        // The layout was adjusted successfully when the memory was allocated.
+        if let Ok(layout) = local.adjust_layout(layout) {
+            // Each allocator only reclaims its own last allocation.
+            unsafe { local.as_inner().deallocate(ptr, layout) }
+            if let Some(overflow) = local.overflow() {
+                unsafe { overflow.deallocate(ptr, layout) }
+            }
         }
     }
 
@@ -29,7 +44,11 @@ unsafe impl Allocator for Bump {
         old_layout: Layout,
         new_layout: Layout,
     ) -> Result<NonNull<[u8]>, AllocError> {
-        unsafe { self.local().as_inner().shrink(ptr, old_layout, new_layout) }
+        let local = self.local();
+        let old_layout = local.adjust_layout(old_layout)?;
+        let new_layout = local.adjust_layout(new_layout)?;
+        let ptr = unsafe { local.as_inner().shrink(ptr, old_layout, new_layout)? };
+        Ok(debug_check(ptr, new_layout))
     }
 
     #[inline]
@@ -39,7 +58,19 @@ unsafe impl Allocator for Bump {
         old_layout: Layout,
         new_layout: Layout,
     ) -> Result<NonNull<[u8]>, AllocError> {
-        unsafe { self.local().as_inner().grow(ptr, old_layout, new_layout) }
+        let local = self.local();
+        let old_layout = local.adjust_layout(old_layout)?;
+        let new_layout = local.adjust_layout(new_layout)?;
+        let ptr = match unsafe { local.as_inner().grow(ptr, old_layout, new_layout) } {
+            Ok(ptr) => ptr,
+            Err(err) => {
+                let overflow = local.overflow().ok_or(err)?;
+                unsafe { grow_into(ptr, old_layout, overflow.allocate(new_layout)?) }
+            }
+        };
+        local.record_alloc();
+        self.count_allocation();
+        Ok(debug_check(ptr, new_layout))
     }
 
     #[inline]
@@ -49,6 +80,48 @@ unsafe impl Allocator for Bump {
         old_layout: Layout,
         new_layout: Layout,
     ) -> Result<NonNull<[u8]>, AllocError> {
-        unsafe { self.local().as_inner().grow_zeroed(ptr, old_layout, new_layout) }
+        let local = self.local();
+        let old_layout = local.adjust_layout(old_layout)?;
+        let new_layout = local.adjust_layout(new_layout)?;
+        let ptr = match unsafe { local.as_inner().grow_zeroed(ptr, old_layout, new_layout) } {
+            Ok(ptr) => ptr,
+            Err(err) => {
+                let overflow = local.overflow().ok_or(err)?;
+                let new_ptr = overflow.allocate_zeroed(new_layout)?;
+                unsafe { grow_into(ptr, old_layout, new_ptr) }
+            }
+        };
+        local.record_alloc();
+        self.count_allocation();
+        Ok(debug_check(ptr, new_layout))
     }
 }
+
+impl Bump {
+    #[inline]
+    fn count_allocation(&self) {
+        if self.inner.count_allocations {
+            self.inner.allocations.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+}
+
+// Checks that a returned block fits `layout`, which is already adjusted to the minimum alignment.
+#[inline]
+fn debug_check(ptr: NonNull<[u8]>, layout: Layout) -> NonNull<[u8]> {
+    debug_assert_eq!(
+        ptr.cast::<u8>().as_ptr() as usize % layout.align(),
+        0,
+        "misaligned block"
+    );
+    debug_assert!(ptr.len() >= layout.size(), "block smaller than its layout");
+    ptr
+}
+
+// Copies a block into `new_ptr` after the primary allocator failed to grow it.
+unsafe fn grow_into(ptr: NonNull<u8>, old_layout: Layout, new_ptr: NonNull<[u8]>) -> NonNull<[u8]> {
+    unsafe {
+        core::ptr::copy_nonoverlapping(ptr.as_ptr(), new_ptr.cast().as_ptr(), old_layout.size());
+    }
+    new_ptr
+}
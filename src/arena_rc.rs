@@ -0,0 +1,109 @@
+use std::{cell::Cell, fmt, marker::PhantomData, mem::ManuallyDrop, ops::Deref, ptr::NonNull};
+
+use crate::Bump;
+
+/// A reference-counted pointer to a value in a [`Bump`], whose count lives in the arena too.
+///
+/// Like [`Rc`](std::rc::Rc), clones share the value, and the value is dropped when the last
+/// clone is dropped. The memory is only reclaimed when the allocator is reset, which the
+/// borrow of the [`Bump`] prevents while any clone is alive. Values in reference cycles
+/// are never dropped, but their memory is still freed by the reset.
+///
+/// The count isn't atomic, so an `ArenaRc` stays on the thread that created it.
+///
+/// Created with [`Bump::alloc_rc`].
+///
+/// # Examples
+///
+/// ```
+/// use bump_local::{ArenaRc, Bump};
+///
+/// let bump = Bump::new();
+/// let shared = bump.alloc_rc([1_u32, 2, 3]);
+/// let nodes = [ArenaRc::clone(&shared), ArenaRc::clone(&shared)];
+///
+/// assert_eq!(ArenaRc::strong_count(&shared), 3);
+/// assert!(ArenaRc::ptr_eq(&nodes[0], &nodes[1]));
+/// ```
+pub struct ArenaRc<'a, T> {
+    ptr: NonNull<RcBox<T>>,
+    // Borrows the allocator and owns a share of the value.
+    _marker: PhantomData<(&'a Bump, T)>,
+}
+
+struct RcBox<T> {
+    count: Cell<usize>,
+    value: ManuallyDrop<T>,
+}
+
+impl<'a, T> ArenaRc<'a, T> {
+    pub(crate) fn new_in(bump: &'a Bump, value: T) -> Self {
+        let rc_box = bump.alloc(RcBox {
+            count: Cell::new(1),
+            value: ManuallyDrop::new(value),
+        });
+        Self {
+            ptr: NonNull::from(rc_box),
+            _marker: PhantomData,
+        }
+    }
+
+    /// Returns the number of clones sharing the value, including `this`.
+    pub fn strong_count(this: &Self) -> usize {
+        this.rc_box().count.get()
+    }
+
+    /// Returns `true` if both point to the same value.
+    pub fn ptr_eq(this: &Self, other: &Self) -> bool {
+        this.ptr == other.ptr
+    }
+
+    #[inline]
+    fn rc_box(&self) -> &RcBox<T> {
+        // SAFETY: The box is allocated in a `Bump` borrowed for `'a`, so it isn't reset.
+        unsafe { self.ptr.as_ref() }
+    }
+}
+
+impl<T> Clone for ArenaRc<'_, T> {
+    fn clone(&self) -> Self {
+        let count = &self.rc_box().count;
+        count.set(
+            count
+                .get()
+                .checked_add(1)
+                .expect("reference count overflow"),
+        );
+        Self {
+            ptr: self.ptr,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<T> Drop for ArenaRc<'_, T> {
+    fn drop(&mut self) {
+        let count = self.rc_box().count.get() - 1;
+        self.rc_box().count.set(count);
+        if count == 0 {
+            // SAFETY: This was the last clone, so the value isn't used anymore,
+            // and it's only dropped once.
+            unsafe { ManuallyDrop::drop(&mut (*self.ptr.as_ptr()).value) }
+        }
+    }
+}
+
+impl<T> Deref for ArenaRc<'_, T> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &T {
+        &self.rc_box().value
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for ArenaRc<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&**self, f)
+    }
+}
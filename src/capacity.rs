@@ -0,0 +1,72 @@
+use crate::BuildError;
+
+/// A validated initial capacity for the per-thread allocators.
+///
+/// A capacity is non-zero and doesn't exceed `isize::MAX`.
+/// Accepted by [`BumpBuilder::bump_capacity`](crate::BumpBuilder::bump_capacity).
+///
+/// # Examples
+///
+/// ```
+/// use bump_local::{Bump, Capacity};
+///
+/// let capacity = Capacity::rounded_up(3000).unwrap();
+/// assert_eq!(capacity.get(), 4096);
+///
+/// let bump = Bump::builder().bump_capacity(capacity).build();
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Capacity(usize);
+
+impl Capacity {
+    /// Validates `capacity` bytes.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BuildError`] if `capacity` is zero or exceeds `isize::MAX`.
+    pub const fn new(capacity: usize) -> Result<Self, BuildError> {
+        if capacity == 0 {
+            Err(BuildError::ZeroCapacity)
+        } else if capacity > isize::MAX as usize {
+            Err(BuildError::CapacityOverflow)
+        } else {
+            Ok(Self(capacity))
+        }
+    }
+
+    /// Validates `capacity` bytes rounded up to the next power of two.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BuildError`] if `capacity` is zero or the rounded capacity exceeds `isize::MAX`.
+    pub const fn rounded_up(capacity: usize) -> Result<Self, BuildError> {
+        // Zero would round up to one.
+        if capacity == 0 {
+            return Err(BuildError::ZeroCapacity);
+        }
+        match capacity.checked_next_power_of_two() {
+            Some(capacity) => Self::new(capacity),
+            None => Err(BuildError::CapacityOverflow),
+        }
+    }
+
+    /// Returns the capacity in bytes.
+    #[inline]
+    pub const fn get(self) -> usize {
+        self.0
+    }
+}
+
+impl TryFrom<usize> for Capacity {
+    type Error = BuildError;
+
+    fn try_from(capacity: usize) -> Result<Self, BuildError> {
+        Self::new(capacity)
+    }
+}
+
+impl From<Capacity> for usize {
+    fn from(capacity: Capacity) -> usize {
+        capacity.0
+    }
+}
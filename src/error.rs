@@ -1,6 +1,7 @@
 use std::fmt;
 
 /// Reset is only allowed when single Bump reference exists
+#[must_use]
 pub struct ResetError;
 
 impl std::error::Error for ResetError {}
@@ -16,3 +17,76 @@ impl fmt::Debug for ResetError {
         <Self as fmt::Display>::fmt(self, f)
     }
 }
+
+/// Invalid [`Bump`](crate::Bump) configuration.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum BuildError {
+    /// The capacity is zero.
+    ZeroCapacity,
+    /// The capacity exceeds `isize::MAX`.
+    CapacityOverflow,
+    /// The [global](crate::global) allocator was already initialized.
+    GlobalInitialized,
+}
+
+impl std::error::Error for BuildError {}
+
+impl fmt::Display for BuildError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::ZeroCapacity => f.write_str("capacity must be non-zero"),
+            Self::CapacityOverflow => f.write_str("capacity exceeds isize::MAX"),
+            Self::GlobalInitialized => f.write_str("global allocator is already initialized"),
+        }
+    }
+}
+
+/// The allocation failed, e.g. because the allocation limit was reached.
+///
+/// With the `allocator_api` or `allocator-api2` feature, this is the `AllocError`
+/// of the corresponding `Allocator` trait.
+#[cfg(not(any(feature = "allocator_api", feature = "allocator-api2")))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AllocError;
+
+#[cfg(not(any(feature = "allocator_api", feature = "allocator-api2")))]
+impl std::error::Error for AllocError {}
+
+#[cfg(not(any(feature = "allocator_api", feature = "allocator-api2")))]
+impl fmt::Display for AllocError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("memory allocation failed")
+    }
+}
+
+/// A [`Bump::checkpoint_all`](crate::Bump::checkpoint_all) or
+/// [`Bump::restore_all`](crate::Bump::restore_all) failed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum SnapshotError {
+    /// Other [`Bump`](crate::Bump) handles exist, see [`ResetError`].
+    Shared,
+    /// A thread's allocations span several chunks, or its overflow allocator.
+    Fragmented,
+    /// A thread's current chunk is gone since the checkpoint, or the thread's allocator is.
+    ChunkReplaced,
+}
+
+impl std::error::Error for SnapshotError {}
+
+impl fmt::Display for SnapshotError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Shared => fmt::Display::fmt(&ResetError, f),
+            Self::Fragmented => f.write_str("allocations span more than one chunk"),
+            Self::ChunkReplaced => f.write_str("chunk was replaced since the checkpoint"),
+        }
+    }
+}
+
+impl From<ResetError> for SnapshotError {
+    fn from(_: ResetError) -> Self {
+        Self::Shared
+    }
+}
@@ -0,0 +1,55 @@
+use std::sync::OnceLock;
+
+use crate::{BuildError, Bump, BumpBuilder};
+
+static GLOBAL: OnceLock<Bump> = OnceLock::new();
+
+/// Returns the process-global [`Bump`] allocator.
+///
+/// It's built with the default configuration on first use, unless [`init_global`]
+/// configured it earlier.
+///
+/// [`Bump::reset_all`] requires exclusive access, which a shared `'static` handle can't give.
+/// Use [`Bump::try_reset_all`] instead, which resets every thread's allocator lazily.
+/// Like [`BumpLocal::reset`](crate::BumpLocal::reset), which resets only the current thread's,
+/// the borrow checker can't verify it: since any code in the process can allocate from the
+/// global allocator, the caller has to make sure that no reference to the reset memory is
+/// used afterwards, including references held by other libraries.
+///
+/// # Examples
+///
+/// ```
+/// let value = bump_local::global().alloc(1);
+/// assert_eq!(*value, 1);
+/// ```
+pub fn global() -> &'static Bump {
+    GLOBAL.get_or_init(Bump::new)
+}
+
+/// Builds the process-global [`Bump`] allocator returned by [`global`] from `builder`.
+///
+/// Call this once at startup, before the global allocator is used.
+///
+/// # Errors
+///
+/// Returns [`BuildError::GlobalInitialized`] if the global allocator already exists.
+///
+/// # Examples
+///
+/// ```
+/// use bump_local::Bump;
+///
+/// bump_local::init_global(Bump::builder().bump_capacity(1 << 20)).unwrap();
+/// assert!(bump_local::global().local().as_inner().chunk_capacity() >= 1 << 20);
+///
+/// assert!(bump_local::init_global(Bump::builder()).is_err());
+/// ```
+pub fn init_global(builder: BumpBuilder) -> Result<(), BuildError> {
+    let mut builder = Some(builder);
+    GLOBAL.get_or_init(|| builder.take().unwrap().build());
+
+    match builder {
+        None => Ok(()),
+        Some(_) => Err(BuildError::GlobalInitialized),
+    }
+}
@@ -0,0 +1,92 @@
+use std::{cell::RefCell, collections::HashSet};
+
+use crate::{Bump, ResetError, storage::ThreadLocal};
+
+/// A string interner that stores each distinct string once in a [`Bump`].
+///
+/// Interning is per thread: every thread deduplicates the strings it interned in its own
+/// allocator, so equal strings interned on different threads are equal but have
+/// different addresses. Compare them by value unless they come from the same thread.
+///
+/// # Examples
+///
+/// ```
+/// use bump_local::Interner;
+///
+/// let mut interner = Interner::new();
+/// let a = interner.intern("key");
+/// let b = interner.intern(&String::from("key"));
+/// assert!(std::ptr::eq(a, b));
+///
+/// interner.reset().unwrap();
+/// ```
+#[derive(Default)]
+pub struct Interner {
+    bump: Bump,
+    // The strings are allocated in `bump`, the sets are cleared when it is reset.
+    strings: ThreadLocal<RefCell<HashSet<&'static str>>>,
+}
+
+impl Interner {
+    /// Creates an interner with its own [`Bump`].
+    pub fn new() -> Self {
+        Self::with_bump(Bump::new())
+    }
+
+    /// Creates an interner that allocates in `bump`.
+    ///
+    /// [`reset`](Self::reset) only succeeds if the interner holds the only handle to `bump`.
+    pub fn with_bump(bump: Bump) -> Self {
+        Self {
+            bump,
+            strings: ThreadLocal::new(),
+        }
+    }
+
+    /// Returns the string equal to `s` interned by the current thread,
+    /// copying `s` into the current thread's allocator if there is none.
+    pub fn intern(&self, s: &str) -> &str {
+        let mut strings = self.strings.get_or(RefCell::default).borrow_mut();
+        if let Some(&interned) = strings.get(s) {
+            return interned;
+        }
+
+        let interned: &str = self.bump.alloc_str_concat(&[s]);
+        // SAFETY: The string lives until `bump` is reset. Only `reset` can reset it while the
+        // interner holds a handle, and it clears the sets before they can be used again.
+        let interned: &'static str = unsafe { &*(interned as *const str) };
+        strings.insert(interned);
+        interned
+    }
+
+    /// Returns the number of strings interned by the current thread.
+    pub fn len(&self) -> usize {
+        self.strings
+            .get()
+            .map_or(0, |strings| strings.borrow().len())
+    }
+
+    /// Returns `true` if the current thread hasn't interned any strings.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Forgets the strings of all threads and resets the allocators they are stored in.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ResetError`] if other handles to the interner's [`Bump`] exist,
+    /// see [`Bump::reset_all`]. The strings are kept in that case, unless a handle
+    /// was upgraded from a [`WeakBump`](crate::WeakBump) during the call.
+    pub fn reset(&mut self) -> Result<(), ResetError> {
+        if self.bump.handle_count() != 1 {
+            return Err(ResetError);
+        }
+        // Cleared first, so the sets can't refer to reset memory if the reset panics,
+        // e.g. in the `Bump`'s metrics sink.
+        for strings in self.strings.iter_mut() {
+            strings.get_mut().clear();
+        }
+        self.bump.reset_all()
+    }
+}
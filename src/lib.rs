@@ -45,23 +45,85 @@
 extern crate alloc;
 
 use std::{
-    cell::UnsafeCell,
-    sync::{
-        Arc,
-        atomic::{AtomicBool, Ordering},
-    },
+    alloc::Layout,
+    cell::{Cell, UnsafeCell},
+    convert::Infallible,
+    ffi::{CStr, CString, NulError},
+    fmt,
+    mem::{self, MaybeUninit},
+    ops::Deref,
+    panic::{RefUnwindSafe, UnwindSafe},
+    pin::Pin,
+    ptr::NonNull,
+    sync::{Arc, Mutex, MutexGuard, PoisonError, Weak, atomic::Ordering},
+    thread::{self, ThreadId},
 };
 
-use thread_local::ThreadLocal;
+#[cfg(feature = "timing")]
+use std::time::{Duration, Instant};
+
+mod arena_rc;
+pub use arena_rc::ArenaRc;
+
+mod capacity;
+pub use capacity::Capacity;
 
 mod error;
-pub use error::ResetError;
+pub use error::{BuildError, ResetError, SnapshotError};
+
+mod global;
+pub use global::{global, init_global};
+
+#[cfg(feature = "interner")]
+mod interner;
+#[cfg(feature = "interner")]
+pub use interner::Interner;
+
+mod scheduler;
+pub use scheduler::ResetScheduler;
+
+mod send_ref;
+pub use send_ref::SendRef;
+
+mod snapshot;
+pub use snapshot::ArenaSnapshot;
+use snapshot::ThreadSnapshot;
+
+mod spill_vec;
+pub use spill_vec::SpillVec;
+
+mod stats;
+use stats::ByteSize;
+pub use stats::{ExceededThresholds, ResetStats, ResetThresholds};
+
+mod vec_builder;
+pub use vec_builder::ArenaVecBuilder;
+
+mod storage;
+use storage::ThreadLocal;
+
+mod sync;
+use sync::{AtomicBool, AtomicUsize, atomic, thread_local};
+
+#[cfg(feature = "release_to_os")]
+mod os;
 
 #[cfg(any(feature = "allocator_api", feature = "allocator-api2"))]
 mod alloc_api;
 
 #[cfg(any(feature = "allocator_api", feature = "allocator-api2"))]
-pub use alloc_api::Allocator;
+pub use alloc_api::{AllocError, Allocator};
+
+#[cfg(not(any(feature = "allocator_api", feature = "allocator-api2")))]
+pub use error::AllocError;
+
+/// Re-export of [`bumpalo::collections`], available with the `collections` feature.
+#[cfg(feature = "collections")]
+pub use bumpalo::collections;
+
+/// Re-export of [`bumpalo::boxed`], available with the `boxed` feature.
+#[cfg(feature = "boxed")]
+pub use bumpalo::boxed;
 
 struct ThreadGuard {
     alive: Arc<AtomicBool>,
@@ -81,22 +143,92 @@ impl Drop for ThreadGuard {
     }
 }
 
+// The order in which thread-local destructors run doesn't matter for the flag.
+// `ThreadLocal` entries outlive their thread and are only reclaimed by `BumpInner::reset_local`
+// under an `ExclusiveGuard`. A thread that is still running its destructors then counts as
+// alive, so its allocator is reset, and dropped by the first reset after the flag is cleared.
+// A new thread that takes over the entry of an exited one before that loses its allocator
+// on the next reset, which is fine since no references can be alive at that point.
 thread_local! {
     static THREAD_GUARD: ThreadGuard = ThreadGuard::new();
 }
 
+// Slot of the `Bump` the current thread accessed last, keyed by `BumpInner::id`.
+//
+// Not used under `loom`, whose threads share the OS thread's `std` thread-locals.
+#[cfg(not(loom))]
+std::thread_local! {
+    static LAST_SLOT: Cell<(usize, *const LocalSlot)> = const { Cell::new((0, std::ptr::null())) };
+    static LAST_SLOT_GUARD: LastSlotGuard = const { LastSlotGuard };
+}
+
+// Clears `LAST_SLOT` when the thread exits and stops `cache_slot` from filling it again.
+//
+// Once the thread's entry is released to the storage, a new thread can take it over, so
+// thread-local destructors that run later must look up their slot again. The guard is
+// first accessed by `cache_slot`, after the storage registered its own thread-exit
+// destructor, and destructors run in reverse order of registration.
+#[cfg(not(loom))]
+struct LastSlotGuard;
+
+#[cfg(not(loom))]
+impl Drop for LastSlotGuard {
+    fn drop(&mut self) {
+        LAST_SLOT.with(|last| last.set((0, std::ptr::null())));
+    }
+}
+
+// Source of `BumpInner::id`, zero is never used.
+static NEXT_ID: std::sync::atomic::AtomicUsize = std::sync::atomic::AtomicUsize::new(1);
+
+/// Returns the liveness flag of the current thread.
+///
+/// The flag is set to `false` with [`Ordering::Release`] when the thread exits.
+/// There is one flag per thread, shared by every [`Bump`] the thread allocates from,
+/// so adjacent per-thread resources can reuse it to coordinate reclamation.
+/// Thread-local destructors that run after the flag was cleared get a new flag that is
+/// already `false`.
+///
+/// # Examples
+///
+/// ```
+/// use std::sync::atomic::Ordering;
+///
+/// let alive = std::thread::spawn(bump_local::current_thread_alive).join().unwrap();
+/// assert!(!alive.load(Ordering::Acquire));
+///
+/// assert!(bump_local::current_thread_alive().load(Ordering::Acquire));
+/// ```
+pub fn current_thread_alive() -> Arc<AtomicBool> {
+    THREAD_GUARD
+        .try_with(|guard| guard.alive.clone())
+        .unwrap_or_else(|_| Arc::new(AtomicBool::new(false)))
+}
+
 /// A thread-safe bump allocator that provides `Sync + Send` semantics.
 ///
 /// Each thread gets its own [`BumpLocal`] instance.
-#[derive(Default, Clone)]
+#[derive(Clone)]
 pub struct Bump {
     inner: Arc<BumpInner>,
 }
 
 impl Bump {
     /// Creates a new [`Bump`] allocator.
+    ///
+    /// Each thread's allocator starts with a capacity of 4 KiB, see [`BumpBuilder::bump_capacity`].
+    /// Earlier versions started with zero capacity, use [`Bump::empty`] for that.
     pub fn new() -> Self {
-        Self::default()
+        Self::builder().build()
+    }
+
+    /// Creates a new [`Bump`] allocator whose threads' allocators start without capacity.
+    ///
+    /// The first allocation on each thread allocates a chunk.
+    pub fn empty() -> Self {
+        let mut builder = Self::builder();
+        builder.bump_capacity = 0;
+        builder.build()
     }
 
     /// Returns a [`BumpBuilder`] for configuring a [`Bump`] allocator.
@@ -127,125 +259,2567 @@ impl Bump {
         self.inner.local()
     }
 
-    /// Resets all threads' bump allocators, deallocating all previously allocated memory.
+    /// Like [`local`](Self::local), but returns an error instead of aborting
+    /// if the current thread's allocator can't be created.
     ///
-    /// # Safety Contract
+    /// Also fails if the [bump capacity](BumpBuilder::bump_capacity)
+    /// exceeds the [allocation limit](BumpBuilder::bump_allocation_limit).
     ///
-    /// - At the moment of reset it must be the only handle to the [`Bump`].
-    /// - Like [`bumpalo::Bump::reset()`], callers must ensure no references to allocated memory
-    ///   are used after calling this method.
-    /// - This does not run any `Drop` implementations.
+    /// # Examples
+    ///
+    /// ```
+    /// use bump_local::Bump;
+    ///
+    /// let bump = Bump::builder()
+    ///     .bump_capacity(4096)
+    ///     .bump_allocation_limit(1024)
+    ///     .build();
+    /// assert!(bump.try_local().is_err());
+    /// ```
     #[inline]
-    pub fn reset_all(&mut self) -> Result<(), ResetError> {
-        match Arc::get_mut(&mut self.inner) {
-            Some(inner) => {
-                inner.reset_all();
-                Ok(())
-            }
-            None => Err(ResetError),
-        }
+    pub fn try_local(&self) -> Result<&BumpLocal, AllocError> {
+        self.inner.try_local()
     }
-}
 
-/// Builder for configuring a [`Bump`] allocator.
-#[derive(Default)]
-pub struct BumpBuilder {
-    threads_capacity: Option<usize>,
-    bump_alloc_limit: Option<usize>,
-    bump_capacity: usize,
-}
+    /// Creates the current thread's allocator and touches the free space of its chunk,
+    /// returning the allocator.
+    ///
+    /// Call this at the start of each worker thread so that neither creating the allocator
+    /// nor the page faults of its first chunk land on the first allocation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::thread;
+    ///
+    /// use bump_local::Bump;
+    ///
+    /// let bump = Bump::builder().bump_capacity(1 << 20).build();
+    ///
+    /// thread::scope(|s| {
+    ///     for _ in 0..4 {
+    ///         s.spawn(|| {
+    ///             let local = bump.prewarm_current();
+    ///             assert!(local.as_inner().chunk_capacity() >= 1 << 20);
+    ///
+    ///             // Work loop allocating from `bump`
+    ///         });
+    ///     }
+    /// });
+    /// ```
+    pub fn prewarm_current(&self) -> &BumpLocal {
+        let local = self.local();
+        local.prefault();
+        local
+    }
 
-impl BumpBuilder {
-    /// Creates a new [`BumpBuilder`] with default configuration.
-    pub fn new() -> Self {
-        Self::default()
+    /// Returns the [`BumpLocal`] for the current thread, skipping the checks of [`local`]
+    /// for a dropped or pending reset.
+    ///
+    /// Meant for hot loops and benchmarks that measure steady-state allocation.
+    /// A reset requested by [`try_reset_all`] is deferred to the next call to [`local`].
+    ///
+    /// [`local`]: Self::local
+    /// [`try_reset_all`]: Self::try_reset_all
+    ///
+    /// # Safety
+    ///
+    /// The current thread's allocator must have been created by [`local`] (or any method
+    /// that allocates) and not been dropped by a reset since, e.g. because the thread
+    /// exited before.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bump_local::Bump;
+    ///
+    /// let bump = Bump::new();
+    /// bump.local();
+    ///
+    /// for i in 0..100 {
+    ///     // SAFETY: The allocator was created above and isn't reset.
+    ///     let local = unsafe { bump.local_initialized_unchecked() };
+    ///     local.as_inner().alloc(i);
+    /// }
+    /// ```
+    #[inline]
+    pub unsafe fn local_initialized_unchecked(&self) -> &BumpLocal {
+        let slot = self.inner.slot();
+        debug_assert!(!slot.needs_init(), "{UNINIT_LOCAL}");
+        slot
     }
 
-    /// Sets the initial capacity hint for the number of threads that will access this allocator.
+    /// Calls `f` with the current thread's `bumpalo::Bump` allocator.
     ///
-    /// This can reduce allocations in the underlying `ThreadLocal` storage when you know
-    /// how many threads will use the allocator.
-    pub fn threads_capacity(mut self, capacity: usize) -> Self {
-        self.threads_capacity = Some(capacity);
-        self
+    /// The allocator is looked up once for the whole closure. The result can't borrow
+    /// from the allocator, which keeps arena references scoped to the closure.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bump_local::Bump;
+    ///
+    /// let bump = Bump::new();
+    /// let sum: u32 = bump.with_local(|arena| {
+    ///     let values = arena.alloc_slice_fill_with(10, |i| i as u32);
+    ///     values.iter().sum()
+    /// });
+    /// assert_eq!(sum, 45);
+    /// ```
+    #[inline]
+    pub fn with_local<R>(&self, f: impl FnOnce(&bumpalo::Bump) -> R) -> R {
+        f(self.local().as_inner())
     }
 
-    /// Sets the allocation limit for each per-thread bump allocator.
+    /// Allocates `val` in the current thread's allocator.
     ///
-    /// Once the limit is reached, further allocations will fail.
-    pub fn bump_allocation_limit(mut self, limit: usize) -> Self {
-        self.bump_alloc_limit = Some(limit);
-        self
+    /// The reference borrows this handle, so the borrow checker rejects calling
+    /// [`reset_all`] on it while the reference is alive. Allocating through a clone instead
+    /// borrows the clone, which then can't be dropped, and [`reset_all`] on the original handle
+    /// fails with [`ResetError`] at runtime. Neither applies to [`BumpLocal::reset`]
+    /// and [`try_reset_all`], which only take `&self`.
+    ///
+    /// [`reset_all`]: Self::reset_all
+    /// [`try_reset_all`]: Self::try_reset_all
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bump_local::Bump;
+    ///
+    /// let mut bump = Bump::new();
+    /// let value = bump.alloc(1);
+    /// *value += 1;
+    ///
+    /// bump.reset_all().unwrap();
+    /// // `value` can't be used anymore
+    /// ```
+    #[inline]
+    #[allow(clippy::mut_from_ref)]
+    pub fn alloc<T>(&self, val: T) -> &mut T {
+        self.local().alloc(val)
     }
 
-    /// Sets the initial capacity for each per-thread bump allocator.
+    /// Allocates a value in the current thread's allocator, constructing it with `f`.
     ///
-    /// This pre-allocates memory for each thread's allocator, which can improve performance
-    /// if you know approximately how much memory each thread will need.
-    pub fn bump_capacity(mut self, capacity: usize) -> Self {
-        self.bump_capacity = capacity;
-        self
+    /// Unlike [`alloc`](Self::alloc), this lets the compiler construct the value directly
+    /// in the arena, avoiding a copy from the stack for large values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bump_local::Bump;
+    ///
+    /// let bump = Bump::new();
+    /// let table = bump.alloc_with(|| [0_u64; 512]);
+    /// assert_eq!(table.len(), 512);
+    /// ```
+    #[inline(always)]
+    #[allow(clippy::mut_from_ref)]
+    pub fn alloc_with<T>(&self, f: impl FnOnce() -> T) -> &mut T {
+        self.local().alloc_with(f)
     }
 
-    /// Builds the [`Bump`] allocator with the configured parameters.
-    pub fn build(self) -> Bump {
-        Bump {
-            inner: Arc::new(BumpInner {
-                locals: match self.threads_capacity {
-                    Some(cap) => ThreadLocal::with_capacity(cap),
-                    None => ThreadLocal::new(),
-                },
-                capacity: self.bump_capacity,
-                alloc_limit: self.bump_alloc_limit,
-            }),
-        }
+    /// Allocates `T::default()` in the current thread's allocator.
+    ///
+    /// The value is constructed with [`alloc_with`](Self::alloc_with),
+    /// so large defaults can be written directly in the arena.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bump_local::Bump;
+    ///
+    /// let bump = Bump::new();
+    /// let counts = bump.alloc_default::<[u32; 32]>();
+    /// assert_eq!(counts[31], 0);
+    /// ```
+    #[inline(always)]
+    #[allow(clippy::mut_from_ref)]
+    pub fn alloc_default<T: Default>(&self) -> &mut T {
+        self.alloc_with(T::default)
     }
-}
 
-/// Per-thread wrapper around a `bumpalo::Bump` allocator.
-pub struct BumpLocal {
-    inner: UnsafeCell<Option<BumpLocalInner>>,
-}
+    /// Allocates `val` in the current thread's allocator and returns a shared reference to it.
+    ///
+    /// The same as [`alloc`](Self::alloc) followed by a reborrow, for read-only data such as
+    /// constants or interned strings that is handed out to many readers. The memory is still
+    /// freed when the allocator is reset.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bump_local::Bump;
+    ///
+    /// let bump = Bump::new();
+    /// let limits = bump.alloc_immut([16_u32, 64]);
+    /// let (first, second) = (limits, limits);
+    /// assert_eq!(first, second);
+    /// ```
+    #[inline]
+    pub fn alloc_immut<T>(&self, val: T) -> &T {
+        self.alloc(val)
+    }
 
-impl BumpLocal {
-    fn new(capacity: usize, limit: Option<usize>, thread_alive: Arc<AtomicBool>) -> Self {
-        let bump = bumpalo::Bump::with_capacity(capacity);
-        bump.set_allocation_limit(limit);
+    /// Allocates `val` in the current thread's allocator and returns it pinned.
+    ///
+    /// Arena memory never moves, so the value stays in place until the allocator is reset.
+    ///
+    /// # Safety
+    ///
+    /// Resetting reuses memory without running `Drop`. If `T` relies on the `Pin` drop guarantee
+    /// (e.g. an intrusive list node), the caller must drop the value in place
+    /// with [`Pin::set`] or [`core::ptr::drop_in_place`] before the allocator is reset.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::marker::PhantomPinned;
+    ///
+    /// use bump_local::Bump;
+    ///
+    /// struct Node {
+    ///     value: u32,
+    ///     _pin: PhantomPinned,
+    /// }
+    ///
+    /// let bump = Bump::new();
+    /// // SAFETY: `Node` doesn't rely on the drop guarantee.
+    /// let node = unsafe {
+    ///     bump.alloc_pin(Node {
+    ///         value: 1,
+    ///         _pin: PhantomPinned,
+    ///     })
+    /// };
+    /// assert_eq!(node.value, 1);
+    /// ```
+    #[inline]
+    #[allow(clippy::mut_from_ref)]
+    pub unsafe fn alloc_pin<T>(&self, val: T) -> Pin<&mut T> {
+        // SAFETY: Arena allocations are never moved, the caller upholds the drop guarantee.
+        unsafe { Pin::new_unchecked(self.local().alloc(val)) }
+    }
 
-        Self {
-            inner: UnsafeCell::new(Some(BumpLocalInner {
-                inner: bump,
-                thread_alive,
-            })),
+    /// Allocates `val` in the current thread's allocator and returns a handle
+    /// that can be sent to another thread.
+    ///
+    /// The receiving thread gets the value back with [`SendRef::into_ref`], which panics
+    /// if `val` was allocated through another `Bump`, or if [`reset_all`], [`reset_where`],
+    /// [`clear_all`] or [`try_reset_all`] ran in between.
+    ///
+    /// [`reset_all`]: Self::reset_all
+    /// [`reset_where`]: Self::reset_where
+    /// [`clear_all`]: Self::clear_all
+    /// [`try_reset_all`]: Self::try_reset_all
+    ///
+    /// # Safety
+    ///
+    /// The value lives in the current thread's allocator, which the current thread
+    /// can reset on its own. `into_ref` can't detect that. The current thread must not call
+    /// [`BumpLocal::reset`] or otherwise reset its allocator through [`BumpLocal::as_inner_mut`]
+    /// until the reference returned by `into_ref` is no longer used.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::thread;
+    ///
+    /// use bump_local::Bump;
+    ///
+    /// let bump = Bump::new();
+    ///
+    /// let sent = thread::scope(|s| {
+    ///     // SAFETY: The spawned thread doesn't reset its allocator.
+    ///     s.spawn(|| unsafe { bump.alloc_send([1, 2, 3]) })
+    ///         .join()
+    ///         .unwrap()
+    /// });
+    ///
+    /// assert_eq!(sent.into_ref(&bump), &[1, 2, 3]);
+    /// ```
+    #[inline]
+    pub unsafe fn alloc_send<T: Send>(&self, val: T) -> SendRef<T> {
+        let epoch = self.inner.epoch.load(Ordering::Acquire);
+        let ptr = NonNull::from(self.local().alloc(val));
+        SendRef::new(ptr, self.inner.id, epoch)
+    }
+
+    /// Allocates memory for `layout` in the current thread's allocator.
+    ///
+    /// This is the same as `Allocator::allocate`, but doesn't require
+    /// the `allocator_api` or `allocator-api2` feature.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AllocError`] if the memory couldn't be allocated,
+    /// e.g. because the [allocation limit] was reached.
+    ///
+    /// [allocation limit]: BumpBuilder::bump_allocation_limit
+    #[inline]
+    pub fn raw_allocate(&self, layout: Layout) -> Result<NonNull<[u8]>, AllocError> {
+        let ptr = self.local().try_alloc_layout(layout)?;
+        Ok(NonNull::slice_from_raw_parts(ptr, layout.size()))
+    }
+
+    /// Allocates `size` bytes aligned to `align` in the current thread's allocator.
+    ///
+    /// A building block for code that does its own layout math, e.g. a serializer
+    /// writing into raw buffers. The memory is uninitialized and is valid for `size` bytes
+    /// until the allocator is reset.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `align` isn't a power of two or the size rounded up to `align`
+    /// exceeds `isize::MAX`. Aborts if the memory couldn't be allocated.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bump_local::Bump;
+    ///
+    /// let bump = Bump::new();
+    /// let ptr = bump.alloc_raw(24, 16);
+    /// assert_eq!(ptr.as_ptr() as usize % 16, 0);
+    /// ```
+    #[inline]
+    pub fn alloc_raw(&self, size: usize, align: usize) -> NonNull<u8> {
+        let layout = Layout::from_size_align(size, align).expect("invalid layout");
+        self.local().alloc_layout(layout)
+    }
+
+    /// Copies `src` into the current thread's allocator and returns a raw pointer to the copy.
+    ///
+    /// For handing arena data to foreign code, where a `&mut [T]` would have its lifetime
+    /// erased anyway. The pointer is valid until the allocator is reset. Nothing ties it
+    /// to the [`Bump`], so it is up to the caller not to use it after a reset.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the size of `src` exceeds `isize::MAX`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bump_local::Bump;
+    ///
+    /// let bump = Bump::new();
+    /// let ptr = bump.alloc_slice_copy_raw(&[1_u32, 2, 3]);
+    /// assert_eq!(ptr.len(), 3);
+    /// // SAFETY: The allocator wasn't reset.
+    /// assert_eq!(unsafe { ptr.as_ref() }, [1, 2, 3]);
+    /// ```
+    #[inline]
+    pub fn alloc_slice_copy_raw<T: Copy>(&self, src: &[T]) -> NonNull<[T]> {
+        NonNull::from(self.local().alloc_slice_copy(src))
+    }
+
+    /// Allocates memory for `layout` in the current thread's allocator and returns
+    /// the pointer with the number of bytes usable through it.
+    ///
+    /// The allocator hands out memory from the end of its chunk towards the start,
+    /// aligning the pointer down to the layout's alignment (or the
+    /// [minimum alignment](BumpBuilder::min_alignment), if larger). The usable size is
+    /// at least `layout.size()` and includes the size rounded up to the alignment and
+    /// the padding skipped above the allocation, which nothing else will use. Allocations served by the
+    /// [overflow allocator](BumpBuilder::overflow_capacity) report exactly `layout.size()`.
+    ///
+    /// # Panics
+    ///
+    /// Calls [`std::alloc::handle_alloc_error`] if the memory couldn't be allocated.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::alloc::Layout;
+    ///
+    /// use bump_local::Bump;
+    ///
+    /// let bump = Bump::new();
+    /// bump.alloc(1_u8);
+    ///
+    /// let (ptr, usable) = bump.alloc_layout_excess(Layout::from_size_align(5, 8).unwrap());
+    /// assert_eq!(ptr.as_ptr() as usize % 8, 0);
+    /// assert!(usable >= 5);
+    /// ```
+    #[inline]
+    pub fn alloc_layout_excess(&self, layout: Layout) -> (NonNull<u8>, usize) {
+        match self.local().try_alloc_layout_excess(layout) {
+            Ok(allocation) => allocation,
+            Err(_) => std::alloc::handle_alloc_error(layout),
         }
     }
 
-    /// Returns a reference to the underlying `bumpalo::Bump` allocator.
+    /// Allocates an uninitialized byte buffer of `len` bytes in the current thread's allocator.
     ///
-    /// The returned reference provides access to all `bumpalo::Bump` allocation methods.
+    /// Use [`alloc_bytes_zeroed`] to get an initialized `&mut [u8]`.
+    ///
+    /// [`alloc_bytes_zeroed`]: Self::alloc_bytes_zeroed
+    ///
+    /// # Panics
+    ///
+    /// Panics if `len` exceeds `isize::MAX`.
     #[inline]
-    pub fn as_inner(&self) -> &bumpalo::Bump {
-        // SAFETY:
-        // - BumpLocal is only constructed inside ThreadLocal,
-        //   which ensures it's only accessed by one thread.
-        // - The returned reference is !Send since bumpalo::Bump is !Sync.
+    #[allow(clippy::mut_from_ref)]
+    pub fn alloc_bytes(&self, len: usize) -> &mut [MaybeUninit<u8>] {
+        self.local().alloc_slice_uninit(len)
+    }
+
+    /// Allocates a zeroed byte buffer of `len` bytes in the current thread's allocator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bump_local::Bump;
+    ///
+    /// let bump = Bump::new();
+    /// let buf = bump.alloc_bytes_zeroed(16);
+    /// assert_eq!(buf, &[0; 16]);
+    /// ```
+    #[inline]
+    #[allow(clippy::mut_from_ref)]
+    pub fn alloc_bytes_zeroed(&self, len: usize) -> &mut [u8] {
+        let buf = self.alloc_bytes(len);
+        // SAFETY: The buffer is valid for `len` bytes.
+        unsafe {
+            buf.as_mut_ptr().write_bytes(0, len);
+            &mut *(buf as *mut [MaybeUninit<u8>] as *mut [u8])
+        }
+    }
+
+    /// Allocates a slice of `len` default values in the current thread's allocator.
+    ///
+    /// The values are allocated contiguously in one step, which is cheaper than allocating
+    /// them one by one when many values are needed at once.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the size of the slice exceeds `isize::MAX`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bump_local::Bump;
+    ///
+    /// let bump = Bump::new();
+    /// let row = bump.alloc_slice_fill_default::<f32>(4);
+    /// assert_eq!(row, [0.0; 4]);
+    /// ```
+    #[inline]
+    #[allow(clippy::mut_from_ref)]
+    pub fn alloc_slice_fill_default<T: Default>(&self, len: usize) -> &mut [T] {
+        self.local().alloc_slice_fill_with(len, |_| T::default())
+    }
+
+    /// Allocates a slice of `len` copies of `value` in the current thread's allocator.
+    ///
+    /// The items are written in a plain loop, which the compiler turns into a `memset`
+    /// for one-byte types.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the size of the slice exceeds `isize::MAX`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bump_local::Bump;
+    ///
+    /// let bump = Bump::new();
+    /// let padding = bump.alloc_slice_fill_copy(4, b' ');
+    /// assert_eq!(padding, b"    ");
+    /// ```
+    #[inline]
+    #[allow(clippy::mut_from_ref)]
+    pub fn alloc_slice_fill_copy<T: Copy>(&self, len: usize, value: T) -> &mut [T] {
+        let dst = self.local().alloc_slice_uninit::<T>(len);
+        for item in dst.iter_mut() {
+            item.write(value);
+        }
+        // SAFETY: Every item was initialized above.
+        unsafe { &mut *(dst as *mut [MaybeUninit<T>] as *mut [T]) }
+    }
+
+    /// Allocates an uninitialized array of `N` items in the current thread's allocator.
+    ///
+    /// Use [`alloc_array_with`](Self::alloc_array_with) to initialize the items right away.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the size of the array exceeds `isize::MAX`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bump_local::Bump;
+    ///
+    /// let bump = Bump::new();
+    /// let header = bump.alloc_array_uninit::<u16, 4>();
+    /// for (i, field) in header.iter_mut().enumerate() {
+    ///     field.write(i as u16);
+    /// }
+    /// ```
+    #[inline]
+    #[allow(clippy::mut_from_ref)]
+    pub fn alloc_array_uninit<T, const N: usize>(&self) -> &mut [MaybeUninit<T>; N] {
+        let dst = self.local().alloc_slice_uninit::<T>(N);
+        // SAFETY: The slice has `N` items, and `[T; N]` has the alignment of `T`.
+        unsafe { &mut *dst.as_mut_ptr().cast::<[MaybeUninit<T>; N]>() }
+    }
+
+    /// Allocates an array of `N` items in the current thread's allocator,
+    /// initializing the item at each index `i` with `f(i)`.
+    ///
+    /// If `f` panics, the items initialized so far are dropped.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the size of the array exceeds `isize::MAX`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bump_local::Bump;
+    ///
+    /// let bump = Bump::new();
+    /// let squares = bump.alloc_array_with::<u32, 4>(|i| (i * i) as u32);
+    /// assert_eq!(squares, &[0, 1, 4, 9]);
+    /// ```
+    #[inline]
+    #[allow(clippy::mut_from_ref)]
+    pub fn alloc_array_with<T, const N: usize>(&self, f: impl FnMut(usize) -> T) -> &mut [T; N] {
+        let dst = self.local().alloc_slice_fill_with(N, f);
+        // SAFETY: The slice has `N` items, and `[T; N]` has the alignment of `T`.
+        unsafe { &mut *dst.as_mut_ptr().cast::<[T; N]>() }
+    }
+
+    /// Moves the items of `vec` into a slice in the current thread's allocator.
+    ///
+    /// The items are copied in bulk and `vec`'s buffer is freed, without dropping them.
+    /// Like every value in the arena, they are not dropped when the allocator is reset,
+    /// so items that own resources, e.g. `String`s, leak them unless dropped in place
+    /// before the reset.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the size of the slice exceeds `isize::MAX`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bump_local::Bump;
+    ///
+    /// let bump = Bump::new();
+    /// let words = bump.alloc_slice_move(vec!["bump", "local"]);
+    /// assert_eq!(words, ["bump", "local"]);
+    /// ```
+    #[inline]
+    #[allow(clippy::mut_from_ref)]
+    pub fn alloc_slice_move<T>(&self, mut vec: Vec<T>) -> &mut [T] {
+        let dst = self.local().alloc_slice_uninit::<T>(vec.len());
+        // SAFETY: `dst` is a new allocation of the same length. Setting the length to zero
+        // moves the items out of `vec`, so they are only dropped through `dst`.
+        unsafe {
+            std::ptr::copy_nonoverlapping(vec.as_ptr(), dst.as_mut_ptr().cast(), vec.len());
+            vec.set_len(0);
+            &mut *(dst as *mut [MaybeUninit<T>] as *mut [T])
+        }
+    }
+
+    /// Allocates a slice in the current thread's allocator and fills it from `iter`.
+    ///
+    /// The slice is allocated once with the length reported by the iterator.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the iterator yields fewer items than its length, or if the size of
+    /// the slice exceeds `isize::MAX`. In debug builds, also panics if it yields more items.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bump_local::Bump;
+    ///
+    /// let bump = Bump::new();
+    /// let squares = bump.alloc_slice_from_exact((1..5).map(|i| i * i));
+    /// assert_eq!(squares, [1, 4, 9, 16]);
+    /// ```
+    #[inline]
+    #[allow(clippy::mut_from_ref)]
+    pub fn alloc_slice_from_exact<T, I>(&self, iter: I) -> &mut [T]
+    where
+        I: IntoIterator<Item = T>,
+        I::IntoIter: ExactSizeIterator,
+    {
+        let mut iter = iter.into_iter();
+        let slice = self.local().alloc_slice_fill_with(iter.len(), |_| {
+            iter.next()
+                .expect("iterator yielded fewer items than its length")
+        });
+        debug_assert!(
+            iter.next().is_none(),
+            "iterator yielded more items than its length"
+        );
+        slice
+    }
+
+    /// Allocates a slice in the current thread's allocator and fills it from `iter`,
+    /// whose length doesn't need to be known.
+    ///
+    /// The items are collected into a temporary heap buffer first, so only the final slice
+    /// takes up arena memory. Prefer [`alloc_slice_from_exact`](Self::alloc_slice_from_exact)
+    /// when the iterator knows its length, it skips the temporary buffer.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the size of the slice exceeds `isize::MAX`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bump_local::Bump;
+    ///
+    /// let bump = Bump::new();
+    /// let evens = bump.alloc_slice_fill_iter((1..10).filter(|i| i % 2 == 0));
+    /// assert_eq!(evens, [2, 4, 6, 8]);
+    /// ```
+    #[inline]
+    #[allow(clippy::mut_from_ref)]
+    pub fn alloc_slice_fill_iter<T, I>(&self, iter: I) -> &mut [T]
+    where
+        I: IntoIterator<Item = T>,
+    {
+        let items: Vec<T> = iter.into_iter().collect();
+        self.alloc_slice_from_exact(items)
+    }
+
+    /// Concatenates `parts` into a single string in the current thread's allocator.
+    ///
+    /// The string is allocated once, without intermediate heap allocations.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the total length exceeds `isize::MAX`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bump_local::Bump;
+    ///
+    /// let bump = Bump::new();
+    /// let greeting = bump.alloc_str_concat(&["Hello, ", "wörld", "!"]);
+    /// assert_eq!(greeting, "Hello, wörld!");
+    /// ```
+    #[allow(clippy::mut_from_ref)]
+    pub fn alloc_str_concat(&self, parts: &[&str]) -> &mut str {
+        let len = parts
+            .iter()
+            .try_fold(0_usize, |len, part| len.checked_add(part.len()))
+            .expect("allocation size overflow");
+
+        let buf = self.alloc_bytes(len);
+        let mut offset = 0;
+        for part in parts {
+            // SAFETY: The parts fit in the buffer, which doesn't overlap them.
+            unsafe {
+                let dst = buf.as_mut_ptr().add(offset).cast::<u8>();
+                std::ptr::copy_nonoverlapping(part.as_ptr(), dst, part.len());
+            }
+            offset += part.len();
+        }
+
+        // SAFETY: The buffer is initialized with a concatenation of valid UTF-8 strings.
+        unsafe {
+            std::str::from_utf8_unchecked_mut(&mut *(buf as *mut [MaybeUninit<u8>] as *mut [u8]))
+        }
+    }
+
+    /// Concatenates `slices` into a single slice in the current thread's allocator.
+    ///
+    /// Like [`alloc_str_concat`](Self::alloc_str_concat), the slice is allocated once,
+    /// without intermediate heap allocations.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the total size exceeds `isize::MAX`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bump_local::Bump;
+    ///
+    /// let bump = Bump::new();
+    /// let message = bump.alloc_concat_slices(&[&[1_u8, 2][..], &[], &[3]]);
+    /// assert_eq!(message, [1, 2, 3]);
+    /// ```
+    #[allow(clippy::mut_from_ref)]
+    pub fn alloc_concat_slices<T: Copy>(&self, slices: &[&[T]]) -> &mut [T] {
+        let len = slices
+            .iter()
+            .try_fold(0_usize, |len, slice| len.checked_add(slice.len()))
+            .expect("allocation size overflow");
+
+        let dst = self.local().alloc_slice_uninit::<T>(len);
+        let mut offset = 0;
+        for slice in slices {
+            // SAFETY: The slices fit in `dst`, which doesn't overlap them, and `T: Copy`.
+            unsafe {
+                let ptr = dst.as_mut_ptr().add(offset).cast::<T>();
+                std::ptr::copy_nonoverlapping(slice.as_ptr(), ptr, slice.len());
+            }
+            offset += slice.len();
+        }
+
+        // SAFETY: All items were initialized.
+        unsafe { &mut *(dst as *mut [MaybeUninit<T>] as *mut [T]) }
+    }
+
+    /// Copies `s` into the current thread's allocator as a nul-terminated C string.
+    ///
+    /// Unlike [`CString::new`](std::ffi::CString::new), this doesn't allocate on the heap,
+    /// except to build the error.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`NulError`] if `s` contains a nul byte. Nothing is allocated in the arena then.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bump_local::Bump;
+    ///
+    /// let bump = Bump::new();
+    /// let name = bump.alloc_cstr("bump").unwrap();
+    /// assert_eq!(name.to_bytes_with_nul(), b"bump\0");
+    ///
+    /// let err = bump.alloc_cstr("bu\0mp").unwrap_err();
+    /// assert_eq!(err.nul_position(), 2);
+    /// ```
+    pub fn alloc_cstr(&self, s: &str) -> Result<&CStr, NulError> {
+        if s.bytes().any(|b| b == 0) {
+            return Err(CString::new(s).expect_err("string contains a nul byte"));
+        }
+
+        let buf = self.local().alloc_slice_uninit::<u8>(s.len() + 1);
+        // SAFETY: The buffer is valid for `s.len() + 1` bytes and doesn't overlap `s`.
+        unsafe {
+            let dst = buf.as_mut_ptr().cast::<u8>();
+            std::ptr::copy_nonoverlapping(s.as_ptr(), dst, s.len());
+            dst.add(s.len()).write(0);
+        }
+
+        // SAFETY: The buffer is initialized and nul-terminated without interior nul bytes.
+        Ok(unsafe {
+            CStr::from_bytes_with_nul_unchecked(&*(buf as *mut [MaybeUninit<u8>] as *const [u8]))
+        })
+    }
+
+    /// Creates an empty [`collections::Vec`] in the current thread's allocator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bump_local::Bump;
+    ///
+    /// let bump = Bump::new();
+    /// let mut vec = bump.alloc_vec();
+    /// vec.push(1);
+    /// ```
+    #[cfg(feature = "collections")]
+    #[inline]
+    pub fn alloc_vec<T>(&self) -> collections::Vec<'_, T> {
+        collections::Vec::new_in(self.local().as_inner())
+    }
+
+    /// Moves `val` into a [`boxed::Box`] in the current thread's allocator.
+    ///
+    /// Unlike other allocations, the value is dropped when the box is dropped.
+    #[cfg(feature = "boxed")]
+    #[inline]
+    pub fn alloc_boxed<T>(&self, val: T) -> boxed::Box<'_, T> {
+        boxed::Box::new_in(val, self.local().as_inner())
+    }
+
+    /// Creates an empty [`hashbrown::HashMap`] that allocates through this [`Bump`].
+    ///
+    /// The map allocates through `&Bump` rather than a clone, so the borrow checker
+    /// prevents resetting while the map is alive. A map holding a clone would instead
+    /// make [`reset_all`](Self::reset_all) fail at runtime until it's dropped.
+    ///
+    /// Like other collections, the map allocates in the allocator of the thread that grows it.
+    /// Memory of outgrown tables is only reclaimed on reset.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bump_local::Bump;
+    ///
+    /// let mut bump = Bump::new();
+    /// {
+    ///     let mut counts = bump.hashmap();
+    ///     for word in ["a", "b", "a"] {
+    ///         *counts.entry(word).or_insert(0) += 1;
+    ///     }
+    ///     assert_eq!(counts["a"], 2);
+    /// }
+    /// bump.reset_all().unwrap();
+    /// ```
+    #[cfg(feature = "hashbrown")]
+    #[inline]
+    pub fn hashmap<K, V>(&self) -> hashbrown::HashMap<K, V, hashbrown::DefaultHashBuilder, &Bump> {
+        hashbrown::HashMap::new_in(self)
+    }
+
+    /// Copies `src` into the current thread's allocator.
+    ///
+    /// This is the way to hand data over between threads: `src` can live anywhere,
+    /// e.g. in another thread's allocator, and the copy lives in the allocator of the thread
+    /// calling this method. Once all copies are made, the source allocator can be reset.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bump_local::Bump;
+    ///
+    /// let producer = Bump::new();
+    /// let consumer = Bump::new();
+    ///
+    /// let data = std::thread::scope(|s| {
+    ///     s.spawn(|| producer.local().as_inner().alloc_slice_copy(&[1, 2, 3]))
+    ///         .join()
+    ///         .unwrap()
+    /// });
+    /// let copy = consumer.copy_into(data);
+    /// assert_eq!(copy, [1, 2, 3]);
+    /// ```
+    #[inline]
+    #[allow(clippy::mut_from_ref)]
+    pub fn copy_into<T: Copy>(&self, src: &[T]) -> &mut [T] {
+        self.local().alloc_slice_copy(src)
+    }
+
+    /// Allocates `val` in the current thread's allocator behind an [`ArenaRc`],
+    /// a reference-counted pointer that can be cloned without heap allocations.
+    #[inline]
+    pub fn alloc_rc<T>(&self, val: T) -> ArenaRc<'_, T> {
+        ArenaRc::new_in(self, val)
+    }
+
+    /// Creates a [`SpillVec`] that keeps up to `N` items inline and spills into this allocator.
+    #[inline]
+    pub fn spill_vec<T, const N: usize>(&self) -> SpillVec<'_, T, N> {
+        SpillVec::new_in(self)
+    }
+
+    /// Creates an [`ArenaVecBuilder`] for building a slice in this allocator incrementally.
+    #[inline]
+    pub fn vec_builder<T>(&self) -> ArenaVecBuilder<'_, T> {
+        ArenaVecBuilder::new_in(self)
+    }
+
+    /// Resets all threads' bump allocators, deallocating all previously allocated memory.
+    ///
+    /// # Safety Contract
+    ///
+    /// - At the moment of reset it must be the only handle to the [`Bump`].
+    ///   [`WeakBump`] handles don't count.
+    /// - Like [`bumpalo::Bump::reset()`], callers must ensure no references to allocated memory
+    ///   are used after calling this method.
+    /// - This does not run any `Drop` implementations.
+    ///
+    /// References to allocated memory borrow the handle they were allocated through,
+    /// so the borrow checker rejects using them after `reset_all`, even when they come from
+    /// a clone (which has to be dropped first). It can't check [`BumpLocal::reset`],
+    /// which only takes `&self`.
+    ///
+    /// Callbacks registered with [`on_before_reset`](Self::on_before_reset) run first.
+    /// The order in which threads' allocators are reset is unspecified.
+    /// The [metrics sink](BumpBuilder::metrics_sink), if any, runs last.
+    #[inline]
+    #[must_use = "the allocators are not reset if other handles exist"]
+    pub fn reset_all(&mut self) -> Result<(), ResetError> {
+        let inner = self.exclusive()?;
+        match &inner.metrics_sink {
+            None => inner.reset_all(),
+            Some(sink) => {
+                let stats = inner.reset_stats();
+                inner.reset_all();
+                sink(&stats);
+            }
+        }
+        Ok(())
+    }
+
+    /// Runs `f` with this [`Bump`], then drops it, returning the result of `f`.
+    ///
+    /// The result can't borrow from the allocator, so everything allocated by `f` is freed
+    /// on return when this was the only handle. Clones made by `f` that outlive it, e.g. in
+    /// threads it spawned, keep the allocators alive until they are dropped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bump_local::Bump;
+    ///
+    /// let total = Bump::new().scoped(|bump| {
+    ///     let values = bump.alloc_slice_from_exact(1..101_u32);
+    ///     values.iter().sum::<u32>()
+    /// });
+    /// assert_eq!(total, 5050);
+    /// ```
+    pub fn scoped<R>(self, f: impl FnOnce(&Bump) -> R) -> R {
+        f(&self)
+    }
+
+    /// Consumes the [`Bump`] and returns copies of the allocated parts of all threads' chunks.
+    ///
+    /// Each item holds the bytes allocated from one chunk, e.g. to write a completed arena
+    /// to a file. Chunks without allocations are skipped, and the order of the chunks is
+    /// unspecified. The bytes are [`MaybeUninit`], since alignment padding and memory from
+    /// [`alloc_bytes`](Self::alloc_bytes) may be uninitialized.
+    ///
+    /// Like [`reset_all`](Self::reset_all), this requires the only handle to the [`Bump`].
+    /// The allocator is dropped either way.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bump_local::Bump;
+    ///
+    /// let bump = Bump::new();
+    /// bump.alloc_slice_fill_copy(4, 7_u8);
+    ///
+    /// let chunks: Vec<_> = bump.into_chunks().unwrap().collect();
+    /// // SAFETY: The only allocation initialized all of its bytes.
+    /// let bytes: Vec<u8> = chunks[0].iter().map(|byte| unsafe { byte.assume_init() }).collect();
+    /// assert_eq!(bytes, [7; 4]);
+    /// ```
+    pub fn into_chunks(
+        mut self,
+    ) -> Result<impl Iterator<Item = Box<[MaybeUninit<u8>]>>, ResetError> {
+        let inner = self.exclusive()?;
+        let mut chunks = Vec::new();
+        for local in inner.locals.iter().filter(|local| !local.needs_init()) {
+            for (ptr, len) in local.allocated_chunk_ranges().filter(|&(_, len)| len > 0) {
+                // SAFETY: The range is allocated from a chunk, and no handle
+                // can allocate or reset while `inner` is held.
+                let bytes =
+                    unsafe { std::slice::from_raw_parts(ptr.cast::<MaybeUninit<u8>>(), len) };
+                chunks.push(Box::from(bytes));
+            }
+        }
+        Ok(chunks.into_iter())
+    }
+
+    /// Copies the allocated memory of all threads' allocators, so
+    /// [`restore_all`](Self::restore_all) can roll the arena back to this point.
+    ///
+    /// Each thread's allocations have to be in its current chunk, which a reset keeps,
+    /// so that they can be written back to the same addresses. Reserving enough
+    /// [capacity](BumpBuilder::bump_capacity) keeps the allocators from growing.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SnapshotError::Shared`] if other handles to the [`Bump`] exist, and
+    /// [`SnapshotError::Fragmented`] if a thread's allocator has grown by another chunk
+    /// or used its [overflow allocator](BumpBuilder::overflow_capacity) since its last reset.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bump_local::Bump;
+    ///
+    /// let mut bump = Bump::new();
+    /// let state: *mut u32 = bump.alloc(1);
+    /// let checkpoint = bump.checkpoint_all().unwrap();
+    ///
+    /// // SAFETY: The allocator wasn't reset.
+    /// unsafe { *state = 2 };
+    /// bump.restore_all(&checkpoint).unwrap();
+    ///
+    /// let (start, len) = bump.local().allocated_chunk_ranges().next().unwrap();
+    /// assert_eq!((start, len), (state.cast_const().cast(), 4));
+    /// // SAFETY: The restore copied the value back to where it was allocated.
+    /// assert_eq!(unsafe { start.cast::<u32>().read() }, 1);
+    /// ```
+    pub fn checkpoint_all(&mut self) -> Result<ArenaSnapshot, SnapshotError> {
+        let inner = self.exclusive()?;
+        inner.checkpoint_all()
+    }
+
+    /// Resets all threads' allocators and copies the memory of `snapshot` back
+    /// to the addresses it was taken from.
+    ///
+    /// Afterwards every thread's allocator holds exactly the allocations it had at the
+    /// [checkpoint](Self::checkpoint_all), and allocates below them. Threads that had
+    /// none are reset, and the allocators of exited threads are kept.
+    ///
+    /// The memory is restored byte by byte. Pointers to arena memory allocated before the
+    /// checkpoint stay valid, since that memory is restored too, but values owning memory
+    /// elsewhere, e.g. a `String`, point to memory that may have been freed or changed since. Like [`reset_all`](Self::reset_all), this doesn't run
+    /// any `Drop` implementations.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SnapshotError::Shared`] if other handles to the [`Bump`] exist, and
+    /// [`SnapshotError::ChunkReplaced`] if the chunk a thread's memory was copied from
+    /// isn't its current chunk anymore, e.g. because the allocator has grown since the
+    /// checkpoint. Nothing is reset in either case.
+    pub fn restore_all(&mut self, snapshot: &ArenaSnapshot) -> Result<(), SnapshotError> {
+        let inner = self.exclusive()?;
+        inner.restore_all(snapshot)
+    }
+
+    /// Registers a callback to run at the start of the next [`reset_all`],
+    /// before any memory is reset.
+    ///
+    /// Callbacks run once, in registration order, on the thread calling `reset_all`.
+    /// [`reset_all_reporting`] runs them too, other resets don't. Callbacks still pending
+    /// when the last handle is dropped are dropped without running.
+    ///
+    /// [`reset_all`]: Self::reset_all
+    /// [`reset_all_reporting`]: Self::reset_all_reporting
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::sync::mpsc;
+    ///
+    /// use bump_local::Bump;
+    ///
+    /// let mut bump = Bump::new();
+    /// let (tx, rx) = mpsc::channel();
+    /// bump.on_before_reset(move || tx.send("flushed").unwrap());
+    ///
+    /// bump.reset_all().unwrap();
+    /// assert_eq!(rx.try_recv(), Ok("flushed"));
+    ///
+    /// bump.reset_all().unwrap();
+    /// assert!(rx.try_recv().is_err());
+    /// ```
+    pub fn on_before_reset(&self, callback: impl FnOnce() + Send + 'static) {
+        self.inner.lock_before_reset().push(Box::new(callback));
+    }
+
+    /// Like [`reset_all`], but only resets the allocators of threads matching `pred`.
+    ///
+    /// Other threads' allocators are left untouched. Allocators of exited threads that match
+    /// are dropped, like in [`reset_all`].
+    ///
+    /// Keeping long-lived data of one thread in an arena that is reset for the others is fragile:
+    /// it breaks as soon as the work moves to another thread. Prefer a separate [`Bump`]
+    /// for data with a different lifetime.
+    ///
+    /// The [`ThreadId`] is that of the thread that created the allocator. With
+    /// [`retain_dead_arenas`] or [`disable_liveness_tracking`], a new thread can take over
+    /// the allocator of an exited thread and is reported under the exited thread's id.
+    ///
+    /// [`reset_all`]: Self::reset_all
+    /// [`retain_dead_arenas`]: BumpBuilder::retain_dead_arenas
+    /// [`disable_liveness_tracking`]: BumpBuilder::disable_liveness_tracking
+    ///
+    /// # Safety Contract
+    ///
+    /// Same as [`reset_all`], for the allocators that are reset.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bump_local::Bump;
+    ///
+    /// let mut bump = Bump::new();
+    /// let coordinator = std::thread::current().id();
+    ///
+    /// bump.local().as_inner().alloc("config");
+    /// std::thread::scope(|s| {
+    ///     s.spawn(|| bump.local().as_inner().alloc("scratch"));
+    /// });
+    ///
+    /// // Reset the workers, keep the coordinator's allocations
+    /// bump.reset_where(|id| id != coordinator).unwrap();
+    /// ```
+    #[must_use = "the allocators are not reset if other handles exist"]
+    pub fn reset_where(&mut self, pred: impl Fn(ThreadId) -> bool) -> Result<(), ResetError> {
+        let inner = self.exclusive()?;
+        inner.reset_where(pred);
+        Ok(())
+    }
+
+    /// Resets the current thread's allocator and marks the others for reset,
+    /// without requiring exclusive access.
+    ///
+    /// Every other thread resets its allocator on its next call to [`local`]
+    /// (or any method that allocates). Use [`threads_pending_reset`] to find out when
+    /// all threads have caught up. Allocators of exited threads stay pending until
+    /// the next [`reset_all`]. Whether the allocators keep their chunks is configured
+    /// with [`BumpBuilder::lazy_reset_mode`].
+    ///
+    /// [`local`]: Self::local
+    /// [`threads_pending_reset`]: Self::threads_pending_reset
+    /// [`reset_all`]: Self::reset_all
+    ///
+    /// # Safety
+    ///
+    /// The borrow checker can't help here, since this only takes `&self`.
+    /// References to memory allocated by the current thread must not be used after this call.
+    /// References to memory allocated by any other thread must not be used after that thread's
+    /// next call to [`local`]. This does not run any `Drop` implementations.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bump_local::Bump;
+    ///
+    /// let bump = Bump::new();
+    /// bump.local().as_inner().alloc(1);
+    ///
+    /// // SAFETY: No references to allocated memory are used afterwards.
+    /// unsafe { bump.try_reset_all() };
+    /// assert!(!bump.reset_pending());
+    /// ```
+    pub unsafe fn try_reset_all(&self) {
+        self.inner.epoch.fetch_add(1, Ordering::Release);
+        self.inner.generation.fetch_add(1, Ordering::AcqRel);
+        // Catches up the current thread
+        self.inner.local();
+    }
+
+    /// Returns `true` if the current thread's allocator is marked for reset
+    /// by [`try_reset_all`](Self::try_reset_all) and hasn't been reset yet.
+    pub fn reset_pending(&self) -> bool {
+        let generation = self.inner.generation.load(Ordering::Acquire);
+        self.inner
+            .locals
+            .get()
+            .is_some_and(|slot| slot.is_pending(generation))
+    }
+
+    /// Returns the number of threads whose allocators are marked for reset
+    /// by [`try_reset_all`](Self::try_reset_all) and haven't been reset yet.
+    ///
+    /// Once this returns zero, every thread has reset its allocator.
+    pub fn threads_pending_reset(&self) -> usize {
+        let generation = self.inner.generation.load(Ordering::Acquire);
+        self.inner
+            .locals
+            .iter()
+            .filter(|slot| slot.is_pending(generation))
+            .count()
+    }
+
+    /// Like [`reset_all`](Self::reset_all), but also reports what was freed.
+    ///
+    /// With the `timing` feature, this also measures how long each thread's allocator
+    /// took to reset, see `ResetStats::per_thread_durations`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bump_local::Bump;
+    ///
+    /// let mut bump = Bump::new();
+    /// bump.alloc_bytes_zeroed(64);
+    ///
+    /// let stats = bump.reset_all_reporting().unwrap();
+    /// assert!(stats.peak_bytes >= 64);
+    /// ```
+    pub fn reset_all_reporting(&mut self) -> Result<ResetStats, ResetError> {
+        let inner = self.exclusive()?;
+        let stats = inner.reset_stats();
+        #[cfg(feature = "timing")]
+        let stats = ResetStats {
+            per_thread_durations: inner.reset_all_timed(),
+            ..stats
+        };
+        #[cfg(not(feature = "timing"))]
+        inner.reset_all();
+        if let Some(sink) = &inner.metrics_sink {
+            sink(&stats);
+        }
+        Ok(stats)
+    }
+
+    /// Returns all threads' bump allocators to their freshly built state.
+    ///
+    /// Unlike [`reset_all`], which keeps the largest chunk each thread has grown to,
+    /// this replaces every live thread's allocator with a new one of the configured
+    /// [`bump_capacity`]. Allocators of exited threads are dropped, like in [`reset_all`].
+    ///
+    /// Use it when steady-state memory has grown too large and should be released.
+    ///
+    /// [`reset_all`]: Self::reset_all
+    /// [`bump_capacity`]: BumpBuilder::bump_capacity
+    ///
+    /// # Safety Contract
+    ///
+    /// Same as [`reset_all`].
+    #[must_use = "the allocators are not reset if other handles exist"]
+    pub fn clear_all(&mut self) -> Result<(), ResetError> {
+        let inner = self.exclusive()?;
+        inner.clear_all();
+        Ok(())
+    }
+
+    /// Shrinks the allocators of live threads that are empty back to the configured
+    /// [`bump_capacity`].
+    ///
+    /// Allocated data can't be moved, so allocators that hold any allocations, including in
+    /// their [overflow allocator](BumpBuilder::overflow_capacity), are left as they are, and allocators of exited threads are left for [`reset_all`] to drop. Call this after
+    /// a spike, once the threads have reset, to reclaim the chunks they grew to.
+    /// Unlike [`clear_all`], no allocation is invalidated.
+    ///
+    /// [`bump_capacity`]: BumpBuilder::bump_capacity
+    /// [`reset_all`]: Self::reset_all
+    /// [`clear_all`]: Self::clear_all
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bump_local::Bump;
+    ///
+    /// let mut bump = Bump::new();
+    /// bump.alloc([0_u8; 1 << 16]);
+    /// bump.reset_all().unwrap();
+    ///
+    /// bump.compact().unwrap();
+    /// assert!(bump.local().as_inner().chunk_capacity() < 1 << 16);
+    /// ```
+    pub fn compact(&mut self) -> Result<(), ResetError> {
+        let inner = self.exclusive()?;
+        inner.compact();
+        Ok(())
+    }
+
+    /// Returns a [`BumpBuilder`] with the configuration this allocator was built with.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bump_local::Bump;
+    ///
+    /// let bump = Bump::builder().bump_capacity(4096).build();
+    ///
+    /// // Same configuration with an allocation limit
+    /// let limited = bump.to_builder().bump_allocation_limit(1 << 20).build();
+    /// ```
+    pub fn to_builder(&self) -> BumpBuilder {
+        let config = self.inner.config();
+        BumpBuilder {
+            threads_capacity: self.inner.threads_capacity,
+            bump_alloc_limit: config.alloc_limit,
+            bump_capacity: config.capacity,
+            retain_dead_arenas: self.inner.retain_dead_arenas,
+            lazy_reset_mode: self.inner.lazy_reset_mode,
+            reset_chunk_size: self.inner.reset_chunk_size,
+            rebalance_on_reset: self.inner.rebalance_on_reset,
+            liveness_disabled: self.inner.liveness_disabled,
+            poison_on_reset: self.inner.poison_on_reset,
+            min_align: self.inner.min_align,
+            overflow_limit: config.overflow_limit,
+            prefault: config.prefault,
+            panic_on_growth: config.panic_on_growth,
+            on_chunk_grow: self.inner.on_chunk_grow.clone(),
+            metrics_sink: self.inner.metrics_sink.clone(),
+            #[cfg(any(feature = "allocator_api", feature = "allocator-api2"))]
+            count_allocations: self.inner.count_allocations,
+            #[cfg(feature = "release_to_os")]
+            release_to_os: self.inner.release_to_os,
+        }
+    }
+
+    /// Changes the [capacity] and [allocation limit] of allocators created from now on.
+    ///
+    /// Existing allocators keep their configuration. The new one applies to threads
+    /// whose allocator is created later, e.g. after an exited thread's allocator was dropped
+    /// on reset, and to allocators rebuilt by [`clear_all`]. All handles share the change.
+    ///
+    /// [capacity]: BumpBuilder::bump_capacity
+    /// [allocation limit]: BumpBuilder::bump_allocation_limit
+    /// [`clear_all`]: Self::clear_all
+    ///
+    /// # Panics
+    ///
+    /// Panics if `capacity` is a `usize` that isn't a valid [`Capacity`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bump_local::Bump;
+    ///
+    /// let mut bump = Bump::new();
+    /// bump.reconfigure(1 << 20, Some(1 << 24));
+    ///
+    /// // Rebuild the allocators with the new configuration
+    /// bump.clear_all().unwrap();
+    /// assert!(bump.local().as_inner().chunk_capacity() >= 1 << 20);
+    /// ```
+    pub fn reconfigure<C>(&self, capacity: C, limit: Option<usize>)
+    where
+        C: TryInto<Capacity>,
+        C::Error: fmt::Debug,
+    {
+        let capacity = capacity.try_into().expect("invalid bump capacity");
+        let mut config = self.inner.lock_config();
+        config.capacity = capacity.get();
+        config.alloc_limit = limit;
+    }
+
+    /// Returns the number of chunks allocated across all threads since the last reset.
+    ///
+    /// See [`BumpLocal::chunks_allocated_since_reset`]. Like [`reset_all`],
+    /// this requires the only handle to the [`Bump`].
+    ///
+    /// [`reset_all`]: Self::reset_all
+    pub fn chunks_allocated_since_reset(&mut self) -> Result<usize, ResetError> {
+        let inner = self.exclusive()?;
+        Ok(inner
+            .locals
+            .iter()
+            .filter(|local| !local.needs_init())
+            .map(|local| local.chunks_allocated_since_reset())
+            .sum())
+    }
+
+    /// Returns the number of bytes reserved by chunks but not allocated, across all threads.
+    ///
+    /// This includes the space left at the end of previously filled chunks.
+    /// A consistently high share of the [capacity] means the chunks are oversized for the workload.
+    /// Like [`reset_all`], this requires the only handle to the [`Bump`].
+    ///
+    /// [capacity]: ResetStats::capacity_bytes
+    /// [`reset_all`]: Self::reset_all
+    pub fn total_wasted_bytes(&mut self) -> Result<usize, ResetError> {
+        let inner = self.exclusive()?;
+        Ok(inner
+            .locals
+            .iter()
+            .filter(|local| !local.needs_init())
+            .map(|local| local.wasted_bytes())
+            .sum())
+    }
+
+    /// Returns `true` if any thread's allocator is [at its allocation limit].
+    ///
+    /// Like [`reset_all`], this requires the only handle to the [`Bump`].
+    ///
+    /// [at its allocation limit]: BumpLocal::at_limit
+    /// [`reset_all`]: Self::reset_all
+    pub fn any_at_limit(&mut self) -> Result<bool, ResetError> {
+        let inner = self.exclusive()?;
+        let at_limit = inner
+            .locals
+            .iter()
+            .any(|local| !local.needs_init() && local.at_limit());
+        Ok(at_limit)
+    }
+
+    /// Reports what [`reset_all`] would free without resetting anything.
+    ///
+    /// Like [`reset_all`], this requires the only handle to the [`Bump`]:
+    /// other threads could otherwise be allocating while their allocators are inspected.
+    ///
+    /// [`reset_all`]: Self::reset_all
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bump_local::Bump;
+    ///
+    /// let mut bump = Bump::new();
+    /// bump.local().as_inner().alloc([0_u8; 64]);
+    ///
+    /// let stats = bump.reset_all_dry_run().unwrap();
+    /// assert_eq!(stats.threads_reset, 1);
+    /// assert!(stats.allocated_bytes >= 64);
+    /// ```
+    pub fn reset_all_dry_run(&mut self) -> Result<ResetStats, ResetError> {
+        let inner = self.exclusive()?;
+        Ok(inner.reset_stats())
+    }
+
+    /// Returns a one-line summary of the memory use across all threads, for logging.
+    ///
+    /// The format isn't stable, use [`reset_all_dry_run`] for the individual numbers.
+    /// Like [`reset_all`], this requires the only handle to the [`Bump`].
+    ///
+    /// [`reset_all_dry_run`]: Self::reset_all_dry_run
+    /// [`reset_all`]: Self::reset_all
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bump_local::Bump;
+    ///
+    /// let mut bump = Bump::builder().bump_capacity(1 << 20).build();
+    /// bump.alloc_bytes(1 << 19);
+    ///
+    /// // "1 thread, 512.0 KiB allocated / 1.0 MiB reserved (49% util), 0 resets"
+    /// println!("{}", bump.memory_report().unwrap());
+    /// ```
+    pub fn memory_report(&mut self) -> Result<String, ResetError> {
+        let inner = self.exclusive()?;
+        let stats = inner.reset_stats();
+        let threads = stats.threads_reset + stats.threads_dropped;
+        // Counts every kind of reset, `generation` only counts `try_reset_all`.
+        let resets = inner.epoch.load(Ordering::Relaxed);
+
+        let utilization = match stats.capacity_bytes {
+            0 => 0,
+            capacity => stats.allocated_bytes.saturating_mul(100) / capacity,
+        };
+        let report = format!(
+            "{} thread{}, {} allocated / {} reserved ({}% util), {} reset{}",
+            threads,
+            if threads == 1 { "" } else { "s" },
+            ByteSize(stats.allocated_bytes),
+            ByteSize(stats.capacity_bytes),
+            utilization,
+            resets,
+            if resets == 1 { "" } else { "s" },
+        );
+        Ok(report)
+    }
+
+    /// Returns how long each thread's allocator took to initialize.
+    ///
+    /// The duration includes allocating the initial [`bump_capacity`], so large capacities
+    /// whose first touch is slow show up here. Like [`reset_all`], this requires the only handle
+    /// to the [`Bump`].
+    ///
+    /// The order of the threads is unspecified and may change between calls,
+    /// e.g. when a new thread takes over the slot of an exited one.
+    ///
+    /// [`bump_capacity`]: BumpBuilder::bump_capacity
+    /// [`reset_all`]: Self::reset_all
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bump_local::Bump;
+    ///
+    /// let mut bump = Bump::new();
+    /// bump.local();
+    ///
+    /// let timings = bump.init_timings().unwrap();
+    /// assert_eq!(timings[0].0, std::thread::current().id());
+    /// ```
+    #[cfg(feature = "timing")]
+    pub fn init_timings(&mut self) -> Result<Vec<(ThreadId, Duration)>, ResetError> {
+        let inner = self.exclusive()?;
+        let timings = inner
+            .locals
+            .iter()
+            .filter_map(|local| local.get())
+            .map(|inner| (inner.thread_id, inner.init_time))
+            .collect();
+        Ok(timings)
+    }
+
+    /// Returns the id of every thread with an allocator, and whether the thread is still alive.
+    ///
+    /// The allocators of exited threads are listed until a reset drops them, or always with
+    /// [`BumpBuilder::retain_dead_arenas`]. Threads are reported as alive if
+    /// [liveness tracking is disabled](BumpBuilder::disable_liveness_tracking).
+    /// Like [`reset_all`], this requires the only handle to the [`Bump`].
+    ///
+    /// The order of the threads is unspecified.
+    ///
+    /// [`reset_all`]: Self::reset_all
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bump_local::Bump;
+    ///
+    /// let mut bump = Bump::new();
+    /// bump.local();
+    ///
+    /// let id = std::thread::current().id();
+    /// assert_eq!(bump.thread_ids().unwrap(), [(id, true)]);
+    /// ```
+    pub fn thread_ids(&mut self) -> Result<Vec<(ThreadId, bool)>, ResetError> {
+        let inner = self.exclusive()?;
+        let ids = inner
+            .locals
+            .iter()
+            .filter_map(|local| local.get())
+            .map(|inner| (inner.thread_id, inner.is_thread_alive()))
+            .collect();
+        Ok(ids)
+    }
+
+    /// Returns the number of allocations made through the `Allocator` implementation,
+    /// across all threads and handles.
+    ///
+    /// `allocate`, `grow` and `grow_zeroed` count once each when they succeed.
+    /// Always zero unless enabled with [`BumpBuilder::count_allocations`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #![cfg_attr(feature = "allocator_api", feature(allocator_api))]
+    /// use std::alloc::Layout;
+    ///
+    /// use bump_local::{Allocator, Bump};
+    ///
+    /// let bump = Bump::builder().count_allocations(true).build();
+    ///
+    /// let small = Layout::new::<[u64; 4]>();
+    /// let ptr = bump.allocate(small).unwrap();
+    /// assert_eq!(bump.allocation_count(), 1);
+    ///
+    /// // SAFETY: `ptr` was allocated with `small`, which is smaller than the new layout.
+    /// unsafe { bump.grow(ptr.cast(), small, Layout::new::<[u64; 8]>()) }.unwrap();
+    /// assert_eq!(bump.allocation_count(), 2);
+    /// ```
+    #[cfg(any(feature = "allocator_api", feature = "allocator-api2"))]
+    pub fn allocation_count(&self) -> usize {
+        self.inner.allocations.load(Ordering::Relaxed)
+    }
+
+    /// Creates a [`WeakBump`] handle that doesn't keep this allocator alive
+    /// and doesn't prevent [`reset_all`] from succeeding.
+    ///
+    /// [`reset_all`]: Self::reset_all
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bump_local::Bump;
+    ///
+    /// let mut bump = Bump::new();
+    /// let weak = bump.downgrade();
+    ///
+    /// if let Some(bump) = weak.upgrade() {
+    ///     bump.local().as_inner().alloc(1);
+    /// }
+    ///
+    /// bump.reset_all().unwrap();
+    /// ```
+    pub fn downgrade(&self) -> WeakBump {
+        WeakBump {
+            inner: Arc::downgrade(&self.inner),
+        }
+    }
+
+    /// Returns the number of handles to this allocator, including this one.
+    ///
+    /// [`reset_all`](Self::reset_all) and the other exclusive methods succeed only if this is 1.
+    /// [`WeakBump`] handles aren't counted. In concurrent code the count is a snapshot that
+    /// may change right after this returns, so a reset can still fail.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bump_local::Bump;
+    ///
+    /// let bump = Bump::new();
+    /// let clone = bump.clone();
+    /// assert_eq!(bump.handle_count(), 2);
+    ///
+    /// drop(clone);
+    /// assert_eq!(bump.handle_count(), 1);
+    /// ```
+    pub fn handle_count(&self) -> usize {
+        Arc::strong_count(&self.inner)
+    }
+
+    /// Acquires exclusive access to the shared state.
+    ///
+    /// Fails if other [`Bump`] handles exist. While the returned guard is alive,
+    /// [`WeakBump::upgrade`] returns `None`.
+    fn exclusive(&mut self) -> Result<ExclusiveGuard<'_>, ResetError> {
+        self.inner.exclusive.store(true, Ordering::Relaxed);
+        // Pairs with the fence in `WeakBump::upgrade`: either the upgrade observes
+        // the flag, or we observe the upgraded strong reference.
+        atomic::fence(Ordering::SeqCst);
+
+        if Arc::strong_count(&self.inner) != 1 {
+            self.inner.exclusive.store(false, Ordering::Release);
+            return Err(ResetError);
+        }
+
+        // Synchronizes with the `Release` decrement of dropped handles.
+        atomic::fence(Ordering::Acquire);
+        Ok(ExclusiveGuard { inner: &self.inner })
+    }
+}
+
+impl Default for Bump {
+    /// Same as [`Bump::new`].
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A non-owning handle to a [`Bump`] allocator.
+///
+/// Unlike [`Bump`], it doesn't count as a handle for [`Bump::reset_all`],
+/// so subsystems that only occasionally allocate can hold it without blocking resets.
+#[derive(Default, Clone)]
+pub struct WeakBump {
+    inner: Weak<BumpInner>,
+}
+
+impl WeakBump {
+    /// Creates a [`WeakBump`] that never upgrades.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Attempts to obtain a [`Bump`] handle.
+    ///
+    /// Returns `None` if all [`Bump`] handles were dropped, or while a method that needs
+    /// the only handle runs on the owning one. That includes the resets, like
+    /// [`Bump::reset_all`], but also e.g. [`Bump::reset_all_dry_run`], [`Bump::memory_report`]
+    /// and [`Bump::compact`], which fail with [`ResetError`] if other handles exist.
+    pub fn upgrade(&self) -> Option<Bump> {
+        let inner = self.inner.upgrade()?;
+        atomic::fence(Ordering::SeqCst);
+
+        if inner.exclusive.load(Ordering::Acquire) {
+            return None;
+        }
+
+        Some(Bump { inner })
+    }
+}
+
+// Proof of exclusive access to the shared state, see `Bump::exclusive`.
+struct ExclusiveGuard<'a> {
+    inner: &'a BumpInner,
+}
+
+impl Deref for ExclusiveGuard<'_> {
+    type Target = BumpInner;
+
+    #[inline]
+    fn deref(&self) -> &BumpInner {
+        self.inner
+    }
+}
+
+impl Drop for ExclusiveGuard<'_> {
+    fn drop(&mut self) {
+        self.inner.exclusive.store(false, Ordering::Release);
+    }
+}
+
+/// Builder for configuring a [`Bump`] allocator.
+pub struct BumpBuilder {
+    threads_capacity: Option<usize>,
+    bump_alloc_limit: Option<usize>,
+    bump_capacity: usize,
+    retain_dead_arenas: bool,
+    lazy_reset_mode: LazyResetMode,
+    reset_chunk_size: Option<usize>,
+    rebalance_on_reset: bool,
+    liveness_disabled: bool,
+    poison_on_reset: bool,
+    min_align: usize,
+    overflow_limit: Option<usize>,
+    prefault: bool,
+    panic_on_growth: bool,
+    on_chunk_grow: Option<ChunkGrowFn>,
+    metrics_sink: Option<MetricsSinkFn>,
+    #[cfg(any(feature = "allocator_api", feature = "allocator-api2"))]
+    count_allocations: bool,
+    #[cfg(feature = "release_to_os")]
+    release_to_os: bool,
+}
+
+impl BumpBuilder {
+    /// Creates a new [`BumpBuilder`] with default configuration.
+    pub fn new() -> Self {
+        Self {
+            threads_capacity: None,
+            bump_alloc_limit: None,
+            bump_capacity: DEFAULT_BUMP_CAPACITY,
+            retain_dead_arenas: false,
+            lazy_reset_mode: LazyResetMode::Reset,
+            reset_chunk_size: None,
+            rebalance_on_reset: false,
+            liveness_disabled: false,
+            poison_on_reset: false,
+            min_align: 1,
+            overflow_limit: None,
+            prefault: false,
+            panic_on_growth: false,
+            on_chunk_grow: None,
+            metrics_sink: None,
+            #[cfg(any(feature = "allocator_api", feature = "allocator-api2"))]
+            count_allocations: false,
+            #[cfg(feature = "release_to_os")]
+            release_to_os: false,
+        }
+    }
+
+    /// Sets the initial capacity hint for the number of threads that will access this allocator.
+    ///
+    /// This can reduce allocations in the underlying `ThreadLocal` storage when you know
+    /// how many threads will use the allocator.
+    #[must_use]
+    pub fn threads_capacity(mut self, capacity: usize) -> Self {
+        self.threads_capacity = Some(capacity);
+        self
+    }
+
+    /// Sets the allocation limit for each per-thread bump allocator.
+    ///
+    /// Once the limit is reached, further allocations will fail.
+    #[must_use]
+    pub fn bump_allocation_limit(mut self, limit: usize) -> Self {
+        self.bump_alloc_limit = Some(limit);
+        self
+    }
+
+    /// Sets the initial capacity for each per-thread bump allocator.
+    ///
+    /// This pre-allocates memory for each thread's allocator, which can improve performance
+    /// if you know approximately how much memory each thread will need.
+    /// Defaults to 4 KiB. Use [`Bump::empty`] for allocators without initial capacity.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `capacity` is a `usize` that isn't a valid [`Capacity`].
+    #[must_use]
+    pub fn bump_capacity<C>(mut self, capacity: C) -> Self
+    where
+        C: TryInto<Capacity>,
+        C::Error: fmt::Debug,
+    {
+        let capacity = capacity.try_into().expect("invalid bump capacity");
+        self.bump_capacity = capacity.get();
+        self
+    }
+
+    /// Sets the initial capacity for each per-thread bump allocator to fit `count` values
+    /// of type `T`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `count * size_of::<T>()` overflows, or isn't a valid [`Capacity`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bump_local::Bump;
+    ///
+    /// let bump = Bump::builder().bump_capacity_for::<u64>(1024).build();
+    /// assert!(bump.local().as_inner().chunk_capacity() >= 8192);
+    /// ```
+    #[must_use]
+    pub fn bump_capacity_for<T>(self, count: usize) -> Self {
+        let capacity = count
+            .checked_mul(mem::size_of::<T>())
+            .expect("bump capacity overflow");
+        self.bump_capacity(capacity)
+    }
+
+    /// Calls `f` whenever an allocation through [`Bump`] makes a thread's allocator
+    /// allocate a new chunk, with the size of the new chunk in bytes.
+    ///
+    /// `f` runs on the allocating thread, right after the allocation. Chunks allocated
+    /// directly through [`BumpLocal::as_inner`] are reported with the next allocation
+    /// through [`Bump`], summed with its chunk if it allocates one too. Chunks of the
+    /// [overflow allocator](Self::overflow_capacity) aren't reported.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::sync::atomic::{AtomicUsize, Ordering};
+    /// use std::sync::Arc;
+    ///
+    /// use bump_local::Bump;
+    ///
+    /// let grown = Arc::new(AtomicUsize::new(0));
+    /// let bump = Bump::builder()
+    ///     .bump_capacity(64)
+    ///     .on_chunk_grow({
+    ///         let grown = grown.clone();
+    ///         move |_, size| {
+    ///             grown.fetch_add(size, Ordering::Relaxed);
+    ///         }
+    ///     })
+    ///     .build();
+    ///
+    /// bump.alloc_bytes(1024);
+    /// assert!(grown.load(Ordering::Relaxed) >= 1024);
+    /// ```
+    #[must_use]
+    pub fn on_chunk_grow(mut self, f: impl Fn(&BumpLocal, usize) + Send + Sync + 'static) -> Self {
+        self.on_chunk_grow = Some(Callback(Arc::new(f)));
+        self
+    }
+
+    /// Sets a callback that receives the [`ResetStats`] of every [`Bump::reset_all`]
+    /// and [`Bump::reset_all_reporting`], e.g. to export them as metrics.
+    ///
+    /// The callback runs on the resetting thread after the reset completed, with the same
+    /// stats `reset_all_reporting` returns. Without a callback, `reset_all` doesn't collect them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::sync::atomic::{AtomicUsize, Ordering};
+    /// use std::sync::Arc;
+    ///
+    /// use bump_local::Bump;
+    ///
+    /// let freed = Arc::new(AtomicUsize::new(0));
+    /// let mut bump = Bump::builder()
+    ///     .metrics_sink({
+    ///         let freed = freed.clone();
+    ///         move |stats| {
+    ///             freed.fetch_add(stats.allocated_bytes, Ordering::Relaxed);
+    ///         }
+    ///     })
+    ///     .build();
+    ///
+    /// bump.alloc_bytes_zeroed(64);
+    /// bump.reset_all().unwrap();
+    /// assert!(freed.load(Ordering::Relaxed) >= 64);
+    /// ```
+    #[must_use]
+    pub fn metrics_sink(mut self, f: impl Fn(&ResetStats) + Send + Sync + 'static) -> Self {
+        self.metrics_sink = Some(Callback(Arc::new(f)));
+        self
+    }
+
+    /// Counts the allocations made through the `Allocator` implementation of [`Bump`],
+    /// see [`Bump::allocation_count`].
+    ///
+    /// This is meant for tests asserting how often collections allocate.
+    /// Requires the `allocator_api` or `allocator-api2` feature.
+    #[cfg(any(feature = "allocator_api", feature = "allocator-api2"))]
+    #[must_use]
+    pub fn count_allocations(mut self, count: bool) -> Self {
+        self.count_allocations = count;
+        self
+    }
+
+    /// Keeps the allocators of exited threads on reset instead of dropping them.
+    ///
+    /// By default, [`Bump::reset_all`] drops the allocators of exited threads.
+    /// With this option they are reset like the allocators of live threads,
+    /// so a new thread that takes over an exited thread's slot reuses its memory.
+    /// This trades memory for fewer allocations in workloads that cycle threads.
+    #[must_use]
+    pub fn retain_dead_arenas(mut self, retain: bool) -> Self {
+        self.retain_dead_arenas = retain;
+        self
+    }
+
+    /// Sets how a thread catches up with [`Bump::try_reset_all`] on its next call to
+    /// [`Bump::local`].
+    ///
+    /// Defaults to [`LazyResetMode::Reset`].
+    #[must_use]
+    pub fn lazy_reset_mode(mut self, mode: LazyResetMode) -> Self {
+        self.lazy_reset_mode = mode;
+        self
+    }
+
+    /// Makes [`Bump::reset_all`] leave every allocator with a single chunk of `size` bytes.
+    ///
+    /// By default a reset keeps the largest chunk each thread has grown to. With this,
+    /// allocators whose chunk differs from `size`, larger or smaller, are replaced with
+    /// a new one, so every thread holds the same memory after each reset regardless of
+    /// the previous cycle's growth. Also applies to [`Bump::reset_where`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is a `usize` that isn't a valid [`Capacity`].
+    #[must_use]
+    pub fn reset_to_chunk_size<C>(mut self, size: C) -> Self
+    where
+        C: TryInto<Capacity>,
+        C::Error: fmt::Debug,
+    {
+        let size = size.try_into().expect("invalid chunk size");
+        self.reset_chunk_size = Some(size.get());
+        self
+    }
+
+    /// Makes [`Bump::reset_all`] size every thread's allocator after its own peak usage.
+    ///
+    /// By default a reset keeps the largest chunk each thread has grown to, so a thread
+    /// that allocated a lot once holds on to that memory, and a thread that outgrows its
+    /// chunk grows it again in every cycle. With this, a thread whose chunk can't hold
+    /// the peak of the cycle that just ended, or is more than four times larger than it,
+    /// gets a new allocator with the peak as its [`base_capacity`]. Each thread converges
+    /// on its own demand, busy threads grow and idle ones shrink. Also applies to
+    /// [`Bump::reset_where`], and is ignored if [`reset_to_chunk_size`] is set.
+    ///
+    /// [`base_capacity`]: BumpLocal::base_capacity
+    /// [`reset_to_chunk_size`]: Self::reset_to_chunk_size
+    #[must_use]
+    pub fn rebalance_on_reset(mut self, rebalance: bool) -> Self {
+        self.rebalance_on_reset = rebalance;
+        self
+    }
+
+    /// Stops tracking whether the threads using the allocator are alive.
+    ///
+    /// Creating a thread's allocator no longer clones the thread's liveness flag,
+    /// and [`Bump::reset_all`] resets the allocators of exited threads instead of dropping them.
+    /// This is the leanest configuration for programs that never cycle threads.
+    #[must_use]
+    pub fn disable_liveness_tracking(mut self) -> Self {
+        self.liveness_disabled = true;
+        self
+    }
+
+    /// Overwrites the allocated memory with `0xDE` bytes whenever an allocator is reset.
+    ///
+    /// A debugging aid for references used after a reset: they read an obviously wrong
+    /// `0xDEDEDEDE...` pattern instead of stale but plausible values. Writing every allocated
+    /// byte makes resets O(allocated bytes), so this only takes effect with `debug_assertions`
+    /// and is a no-op in release builds.
+    #[must_use]
+    pub fn poison_on_reset(mut self) -> Self {
+        self.poison_on_reset = true;
+        self
+    }
+
+    /// Adds a per-thread overflow allocator that holds up to `capacity` bytes.
+    ///
+    /// When an allocation doesn't fit within the [allocation limit], it is made
+    /// in the overflow allocator instead of failing. The overflow allocator only reserves memory
+    /// once it's used, and is reset together with the primary allocator. Its usage is reported
+    /// separately in [`ResetStats::overflow_bytes`].
+    ///
+    /// This applies to the allocation methods of [`Bump`] and its `Allocator` implementation,
+    /// but not to allocations made directly through [`BumpLocal::as_inner`].
+    ///
+    /// [allocation limit]: Self::bump_allocation_limit
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bump_local::Bump;
+    ///
+    /// let mut bump = Bump::builder()
+    ///     .bump_capacity(1024)
+    ///     .bump_allocation_limit(4096)
+    ///     .overflow_capacity(1 << 20)
+    ///     .build();
+    ///
+    /// bump.alloc([0_u8; 1 << 16]);
+    /// assert!(bump.reset_all_dry_run().unwrap().overflow_bytes >= 1 << 16);
+    /// ```
+    #[must_use]
+    pub fn overflow_capacity(mut self, capacity: usize) -> Self {
+        self.overflow_limit = Some(capacity);
+        self
+    }
+
+    /// Touches every page of each thread's initial chunk when its allocator is created.
+    ///
+    /// The OS then backs the chunk with physical memory up front, so the first allocations
+    /// don't take page faults. Like [`Bump::prewarm_current`], this moves the cost to the
+    /// creation of the allocator, which takes longer, and the whole [`bump_capacity`] becomes
+    /// resident even if it's never used. Allocators rebuilt by [`Bump::clear_all`] are
+    /// touched as well.
+    ///
+    /// [`bump_capacity`]: Self::bump_capacity
+    #[must_use]
+    pub fn prefault(mut self, prefault: bool) -> Self {
+        self.prefault = prefault;
+        self
+    }
+
+    /// Panics when an allocation made through [`Bump`] makes a thread's allocator grow
+    /// beyond its current chunk.
+    ///
+    /// Meant for tuning the [`bump_capacity`] in tests and development builds: increase it
+    /// until no allocation panics. The panic happens after the allocation, reporting by how
+    /// many bytes the allocator grew. Allocations made directly through
+    /// [`BumpLocal::as_inner`] aren't checked.
+    ///
+    /// [`bump_capacity`]: Self::bump_capacity
+    #[must_use]
+    pub fn panic_on_growth(mut self) -> Self {
+        self.panic_on_growth = true;
+        self
+    }
+
+    /// Aligns every allocation to at least `align` bytes.
+    ///
+    /// This applies to the allocation methods of [`Bump`] and its `Allocator` implementation,
+    /// but not to allocations made directly through [`BumpLocal::as_inner`].
+    /// Each allocation with a smaller alignment can waste up to `align - 1` bytes of padding.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `align` is not a power of two.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bump_local::Bump;
+    ///
+    /// let bump = Bump::builder().min_alignment(64).build();
+    /// let value = bump.alloc(1_u8);
+    /// assert_eq!(value as *mut u8 as usize % 64, 0);
+    /// ```
+    #[must_use]
+    pub fn min_alignment(mut self, align: usize) -> Self {
+        assert!(align.is_power_of_two(), "alignment must be a power of two");
+        self.min_align = align;
+        self
+    }
+
+    /// Returns the memory of reset allocators to the OS.
+    ///
+    /// Resetting keeps each thread's largest chunk. With this option, [`Bump::reset_all`]
+    /// and [`Bump::reset_where`] also tell the OS that the chunk's pages are unused
+    /// (`madvise(MADV_DONTNEED)`), so they no longer count towards the resident set
+    /// until they are allocated again. Their contents are unspecified afterwards, like
+    /// the contents of any reset memory. This is a no-op on platforms without `madvise`.
+    #[cfg(feature = "release_to_os")]
+    #[must_use]
+    pub fn release_to_os_on_reset(mut self, release: bool) -> Self {
+        self.release_to_os = release;
+        self
+    }
+
+    /// Builds the [`Bump`] allocator with the configured parameters.
+    pub fn build(self) -> Bump {
+        Bump {
+            inner: Arc::new(BumpInner {
+                locals: match self.threads_capacity {
+                    Some(cap) => ThreadLocal::with_capacity(cap),
+                    None => ThreadLocal::new(),
+                },
+                threads_capacity: self.threads_capacity,
+                config: Mutex::new(BumpConfig {
+                    capacity: self.bump_capacity,
+                    alloc_limit: self.bump_alloc_limit,
+                    overflow_limit: self.overflow_limit,
+                    prefault: self.prefault,
+                    panic_on_growth: self.panic_on_growth,
+                }),
+                retain_dead_arenas: self.retain_dead_arenas,
+                lazy_reset_mode: self.lazy_reset_mode,
+                reset_chunk_size: self.reset_chunk_size,
+                reset_chunk_bytes: AtomicUsize::new(0),
+                rebalance_on_reset: self.rebalance_on_reset,
+                liveness_disabled: self.liveness_disabled,
+                poison_on_reset: self.poison_on_reset,
+                min_align: self.min_align,
+                on_chunk_grow: self.on_chunk_grow,
+                metrics_sink: self.metrics_sink,
+                #[cfg(any(feature = "allocator_api", feature = "allocator-api2"))]
+                count_allocations: self.count_allocations,
+                #[cfg(any(feature = "allocator_api", feature = "allocator-api2"))]
+                allocations: AtomicUsize::new(0),
+                #[cfg(feature = "release_to_os")]
+                release_to_os: self.release_to_os,
+                exclusive: AtomicBool::new(false),
+                generation: AtomicUsize::new(0),
+                epoch: AtomicUsize::new(0),
+                before_reset: Mutex::new(Vec::new()),
+                id: NEXT_ID.fetch_add(1, Ordering::Relaxed),
+            }),
+        }
+    }
+}
+
+impl Default for BumpBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// How a thread's allocator is reset after [`Bump::try_reset_all`] marked it,
+/// see [`BumpBuilder::lazy_reset_mode`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LazyResetMode {
+    /// Resets the allocator, keeping the largest chunk it has grown to, like [`Bump::reset_all`].
+    #[default]
+    Reset,
+    /// Replaces the allocator with a new one of the configured
+    /// [`bump_capacity`](BumpBuilder::bump_capacity), like [`Bump::clear_all`].
+    Reinit,
+}
+
+const DEFAULT_BUMP_CAPACITY: usize = 4096;
+
+// See `BumpBuilder::poison_on_reset`.
+#[cfg(debug_assertions)]
+const POISON_BYTE: u8 = 0xDE;
+
+// Smallest capacity `BumpBuilder::rebalance_on_reset` shrinks an allocator to.
+const MIN_REBALANCE_CAPACITY: usize = 1024;
+
+// See `BumpBuilder::metrics_sink`.
+type MetricsSinkFn = Callback<dyn Fn(&ResetStats) + Send + Sync>;
+
+// See `BumpBuilder::on_chunk_grow`.
+type ChunkGrowFn = Callback<dyn Fn(&BumpLocal, usize) + Send + Sync>;
+
+// A user callback that keeps the types holding it unwind safe.
+// The allocator's state stays consistent if the callback panics.
+struct Callback<F: ?Sized>(Arc<F>);
+
+impl<F: ?Sized> UnwindSafe for Callback<F> {}
+impl<F: ?Sized> RefUnwindSafe for Callback<F> {}
+
+impl<F: ?Sized> Clone for Callback<F> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<F: ?Sized> Deref for Callback<F> {
+    type Target = F;
+
+    #[inline]
+    fn deref(&self) -> &F {
+        &self.0
+    }
+}
+
+// Panic message for accessing the allocator of a `BumpLocal` that was dropped by a reset.
+const UNINIT_LOCAL: &str = "BumpLocal accessed before initialization; call Bump::local() first";
+
+/// Per-thread wrapper around a `bumpalo::Bump` allocator.
+pub struct BumpLocal {
+    inner: UnsafeCell<Option<BumpLocalInner>>,
+}
+
+impl BumpLocal {
+    fn new(inner: BumpLocalInner) -> Self {
+        Self {
+            inner: UnsafeCell::new(Some(inner)),
+        }
+    }
+
+    /// Returns a reference to the underlying `bumpalo::Bump` allocator.
+    ///
+    /// The returned reference provides access to all `bumpalo::Bump` allocation methods.
+    #[inline]
+    pub fn as_inner(&self) -> &bumpalo::Bump {
+        // SAFETY:
+        // - BumpLocal is only constructed inside ThreadLocal,
+        //   which ensures it's only accessed by one thread.
+        // - The returned reference is !Send since bumpalo::Bump is !Sync.
         // - The reference lifetime is bound to the parent Bump allocator.
-        unsafe { &(*self.inner.get()).as_ref().unwrap().inner }
+        unsafe { &(*self.inner.get()).as_ref().expect(UNINIT_LOCAL).inner }
+    }
+
+    /// Returns a mutable reference to the underlying `bumpalo::Bump` allocator.
+    ///
+    /// This gives access to `bumpalo::Bump` methods that take `&mut self`,
+    /// such as `iter_allocated_chunks`.
+    ///
+    /// # Safety
+    ///
+    /// - No reference returned by [`as_inner`] may be used while the returned reference is alive.
+    /// - `&mut self` methods that invalidate allocations (like `reset`) have the same contract
+    ///   as [`reset`]: no references to allocated memory may be used afterwards.
+    ///
+    /// [`as_inner`]: Self::as_inner
+    /// [`reset`]: Self::reset
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bump_local::Bump;
+    ///
+    /// let bump = Bump::new();
+    /// let local = bump.local();
+    /// local.as_inner().alloc(1_u8);
+    ///
+    /// // SAFETY: No references from `as_inner` are alive.
+    /// let chunks = unsafe { local.as_inner_mut() }.iter_allocated_chunks().count();
+    /// assert_eq!(chunks, 1);
+    /// ```
+    #[inline]
+    #[allow(clippy::mut_from_ref)]
+    pub unsafe fn as_inner_mut(&self) -> &mut bumpalo::Bump {
+        // SAFETY:
+        // - ThreadLocal ensures single-thread access to this BumpLocal.
+        // - The caller guarantees no other references to the allocator are alive.
+        unsafe { &mut (*self.inner.get()).as_mut().expect(UNINIT_LOCAL).inner }
+    }
+
+    /// Resets the allocator, deallocating all previously allocated memory.
+    ///
+    /// # Note
+    ///
+    /// - This does not run any `Drop` implementations.
+    /// - Like [`bumpalo::Bump::reset()`], callers must ensure no references to allocated memory
+    ///   are used after calling this method.
+    #[inline]
+    pub fn reset(&self) {
+        // SAFETY: ThreadLocal ensures single-thread access to this BumpLocal.
+        unsafe {
+            let inner = (*self.inner.get()).as_mut().expect(UNINIT_LOCAL);
+            inner.inner.reset();
+            if let Some(overflow) = &mut inner.overflow {
+                overflow.reset();
+            }
+            inner.peak_bytes.set(0);
+            inner.chunk_bytes.set(inner.inner.allocated_bytes());
+        }
+    }
+
+    /// Returns the highest number of bytes allocated since the last reset.
+    ///
+    /// The peak is recorded by the allocation methods of [`Bump`]. Allocations made directly
+    /// through [`as_inner`](Self::as_inner) are only accounted for when they are still allocated.
+    pub fn peak_allocated_bytes(&self) -> usize {
+        let peak = self.get().map_or(0, |inner| inner.peak_bytes.get());
+        peak.max(self.allocated_bytes())
+    }
+
+    /// Returns the capacity the allocator was created with.
+    ///
+    /// This is the [`bump_capacity`] at the time the allocator was created or last rebuilt,
+    /// e.g. by [`Bump::clear_all`], while [`bumpalo::Bump::chunk_capacity`] reflects the chunks
+    /// it has grown to since. Returns 0 if the allocator wasn't created yet.
+    ///
+    /// [`bump_capacity`]: BumpBuilder::bump_capacity
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bump_local::Bump;
+    ///
+    /// let bump = Bump::builder().bump_capacity(64).build();
+    /// bump.alloc_bytes(1024);
+    /// assert_eq!(bump.local().base_capacity(), 64);
+    /// ```
+    pub fn base_capacity(&self) -> usize {
+        self.get().map_or(0, |inner| inner.base_capacity)
+    }
+
+    // Allocates through the underlying allocator, recording the allocation.
+    #[inline]
+    fn alloc_layout(&self, layout: Layout) -> NonNull<u8> {
+        let layout = self
+            .adjust_layout(layout)
+            .expect("allocation size overflow");
+        let ptr = match self.overflow() {
+            None => self.as_inner().alloc_layout(layout),
+            Some(overflow) => match self.as_inner().try_alloc_layout(layout) {
+                Ok(ptr) => ptr,
+                Err(_) => overflow.alloc_layout(layout),
+            },
+        };
+        self.record_alloc();
+        ptr
+    }
+
+    #[inline]
+    fn try_alloc_layout(&self, layout: Layout) -> Result<NonNull<u8>, AllocError> {
+        let layout = self.adjust_layout(layout)?;
+        let ptr = match self.as_inner().try_alloc_layout(layout) {
+            Ok(ptr) => ptr,
+            Err(_) => self
+                .overflow()
+                .ok_or(AllocError)?
+                .try_alloc_layout(layout)
+                .map_err(|_| AllocError)?,
+        };
+        self.record_alloc();
+        Ok(ptr)
+    }
+
+    // Like `try_alloc_layout`, also returning the bytes available at the pointer,
+    // up to where the allocator's pointer was before the allocation.
+    fn try_alloc_layout_excess(&self, layout: Layout) -> Result<(NonNull<u8>, usize), AllocError> {
+        let before = self.current_chunk();
+        let ptr = self.try_alloc_layout(layout)?;
+
+        let usable = match (before, self.current_chunk()) {
+            (Some((top, end)), Some((ptr_after, end_after)))
+                if ptr_after == ptr.as_ptr() && end_after == end =>
+            {
+                top as usize - ptr_after as usize
+            }
+            // Allocated at the top of a new chunk.
+            (_, Some((ptr_after, end_after))) if ptr_after == ptr.as_ptr() => {
+                end_after as usize - ptr_after as usize
+            }
+            // Allocated by the overflow allocator.
+            _ => layout.size(),
+        };
+        Ok((ptr, usable))
+    }
+
+    fn prefault(&self) {
+        prefault(self.as_inner());
+    }
+
+    // The bump pointer and the end of the primary allocator's current chunk.
+    fn current_chunk(&self) -> Option<(*mut u8, *mut u8)> {
+        // SAFETY: No allocations are performed while the iterator is alive.
+        let (ptr, len) = unsafe { self.as_inner().iter_allocated_chunks_raw() }.next()?;
+        Some((ptr, ptr.wrapping_add(len)))
+    }
+
+    // The overflow allocator, if configured.
+    #[inline]
+    fn overflow(&self) -> Option<&bumpalo::Bump> {
+        self.get().and_then(|inner| inner.overflow.as_ref())
+    }
+
+    #[inline]
+    #[allow(clippy::mut_from_ref)]
+    fn alloc<T>(&self, val: T) -> &mut T {
+        self.alloc_with(|| val)
+    }
+
+    #[inline(always)]
+    #[allow(clippy::mut_from_ref)]
+    fn alloc_with<T>(&self, f: impl FnOnce() -> T) -> &mut T {
+        // Writing the result of `f` right away lets it be constructed in place.
+        #[inline(always)]
+        unsafe fn write_with<T>(ptr: *mut T, f: impl FnOnce() -> T) {
+            unsafe { ptr.write(f()) }
+        }
+
+        let ptr = self.alloc_layout(Layout::new::<T>()).cast::<T>();
+        // SAFETY: The allocation is valid and aligned for `T`.
+        unsafe {
+            write_with(ptr.as_ptr(), f);
+            &mut *ptr.as_ptr()
+        }
+    }
+
+    // # Panics
+    //
+    // Panics if the size of the slice exceeds `isize::MAX`.
+    #[inline]
+    #[allow(clippy::mut_from_ref)]
+    fn alloc_slice_uninit<T>(&self, len: usize) -> &mut [MaybeUninit<T>] {
+        let layout = Layout::array::<T>(len).expect("allocation size overflow");
+        // Empty slices don't need memory, but the allocator would still align its pointer.
+        let ptr = if layout.size() == 0 {
+            NonNull::<T>::dangling().cast()
+        } else {
+            self.alloc_layout(layout)
+        };
+
+        // SAFETY: The allocation is valid for `len` items, and `MaybeUninit` doesn't
+        // require initialization.
+        unsafe { std::slice::from_raw_parts_mut(ptr.as_ptr().cast(), len) }
+    }
+
+    #[inline]
+    #[allow(clippy::mut_from_ref)]
+    fn alloc_slice_fill_with<T>(&self, len: usize, mut f: impl FnMut(usize) -> T) -> &mut [T] {
+        // Drops the initialized items if `f` panics, the arena never would.
+        struct Guard<T> {
+            ptr: *mut T,
+            initialized: usize,
+        }
+
+        impl<T> Drop for Guard<T> {
+            fn drop(&mut self) {
+                // SAFETY: The first `initialized` items were written and not handed out.
+                unsafe {
+                    let items = std::ptr::slice_from_raw_parts_mut(self.ptr, self.initialized);
+                    std::ptr::drop_in_place(items);
+                }
+            }
+        }
+
+        let ptr = self.alloc_slice_uninit::<T>(len).as_mut_ptr().cast::<T>();
+        let mut guard = Guard {
+            ptr,
+            initialized: 0,
+        };
+        for i in 0..len {
+            // SAFETY: The allocation is valid for `len` items.
+            unsafe { ptr.add(i).write(f(i)) }
+            guard.initialized += 1;
+        }
+        mem::forget(guard);
+        // SAFETY: All items were initialized.
+        unsafe { std::slice::from_raw_parts_mut(ptr, len) }
+    }
+
+    #[inline]
+    #[allow(clippy::mut_from_ref)]
+    fn alloc_slice_copy<T: Copy>(&self, src: &[T]) -> &mut [T] {
+        let dst = self.alloc_slice_uninit::<T>(src.len());
+        // SAFETY: `dst` is a new allocation of the same length, and `T: Copy`.
+        unsafe {
+            std::ptr::copy_nonoverlapping(src.as_ptr(), dst.as_mut_ptr().cast(), src.len());
+            &mut *(dst as *mut [MaybeUninit<T>] as *mut [T])
+        }
+    }
+
+    // Raises the alignment to the configured minimum.
+    #[inline]
+    fn adjust_layout(&self, layout: Layout) -> Result<Layout, AllocError> {
+        let min_align = self.get().map_or(1, |inner| inner.min_align);
+        if layout.align() >= min_align {
+            return Ok(layout);
+        }
+        layout.align_to(min_align).map_err(|_| AllocError)
+    }
+
+    #[inline]
+    fn record_alloc(&self) {
+        if let Some(inner) = self.get() {
+            let allocated = self.allocated_bytes();
+            if allocated > inner.peak_bytes.get() {
+                inner.peak_bytes.set(allocated);
+            }
+
+            // Growth is only tracked if something observes it.
+            let track_growth = cfg!(feature = "profiling")
+                || inner.on_chunk_grow.is_some()
+                || inner.panic_on_growth;
+            if track_growth {
+                let chunk_bytes = inner.inner.allocated_bytes();
+                let last = inner.chunk_bytes.replace(chunk_bytes);
+                if chunk_bytes > last {
+                    #[cfg(feature = "profiling")]
+                    profiling::scope!("bump_local::chunk_grow");
+
+                    if let Some(on_chunk_grow) = &inner.on_chunk_grow {
+                        on_chunk_grow(self, chunk_bytes - last);
+                    }
+                    if inner.panic_on_growth {
+                        panic!(
+                            "bump allocator grew by {} bytes beyond its {} bytes of chunks",
+                            chunk_bytes - last,
+                            last
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    /// Returns the number of chunks allocated since the last reset.
+    ///
+    /// Resetting keeps only the current chunk, so this is the number of chunks in addition to it.
+    /// A workload that allocates new chunks every cycle is outgrowing the retained chunk,
+    /// and would benefit from a larger [`bump_capacity`].
+    ///
+    /// [`bump_capacity`]: BumpBuilder::bump_capacity
+    pub fn chunks_allocated_since_reset(&self) -> usize {
+        // SAFETY: No allocations are performed while the iterator is alive.
+        let chunks = unsafe { self.as_inner().iter_allocated_chunks_raw() }.count();
+        chunks.saturating_sub(1)
+    }
+
+    /// Returns the start and length of the allocated part of each chunk,
+    /// including the chunks of the [overflow allocator].
+    ///
+    /// The ranges are collected up front, so allocating while iterating is fine,
+    /// but they don't include allocations made after this call. Useful for annotating
+    /// arena memory for sanitizers or Valgrind.
+    ///
+    /// [overflow allocator]: BumpBuilder::overflow_capacity
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bump_local::Bump;
+    ///
+    /// let bump = Bump::new();
+    /// let value: *const u64 = bump.alloc(1_u64);
+    ///
+    /// let (start, len) = bump.local().allocated_chunk_ranges().next().unwrap();
+    /// assert!(start <= value.cast() && value.cast() < start.wrapping_add(len));
+    /// ```
+    pub fn allocated_chunk_ranges(&self) -> impl Iterator<Item = (*const u8, usize)> {
+        let mut ranges = Vec::new();
+        for bump in std::iter::once(self.as_inner()).chain(self.overflow()) {
+            // SAFETY: No allocations are performed while the iterator is alive.
+            let chunks = unsafe { bump.iter_allocated_chunks_raw() };
+            ranges.extend(chunks.map(|(ptr, len)| (ptr.cast_const(), len)));
+        }
+        ranges.into_iter()
+    }
+
+    /// Returns `true` if the allocator can't grow by another chunk without exceeding
+    /// its [allocation limit].
+    ///
+    /// Allocations that don't fit in the current chunk may then fail, and the infallible
+    /// allocation methods abort the process. Polling this lets code flush its work
+    /// before that happens. Always `false` without a limit.
+    ///
+    /// [allocation limit]: BumpBuilder::bump_allocation_limit
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bump_local::Bump;
+    ///
+    /// let bump = Bump::builder().bump_allocation_limit(1 << 20).build();
+    /// bump.local().as_inner().alloc(1);
+    /// assert!(!bump.local().at_limit());
+    /// ```
+    pub fn at_limit(&self) -> bool {
+        let bump = self.as_inner();
+        let Some(limit) = bump.allocation_limit() else {
+            return false;
+        };
+
+        // SAFETY: No allocations are performed while the iterator is alive.
+        let used = unsafe { bump.iter_allocated_chunks_raw() }
+            .next()
+            .map_or(0, |(_, len)| len);
+        // The allocator doubles the size of the current chunk when it grows.
+        let next_chunk = (used + bump.chunk_capacity()).saturating_mul(2);
+
+        bump.allocated_bytes().saturating_add(next_chunk) > limit
+    }
+
+    // Overwrites the allocated memory of all chunks, see `BumpBuilder::poison_on_reset`.
+    #[cfg(debug_assertions)]
+    fn poison(&self) {
+        for bump in std::iter::once(self.as_inner()).chain(self.overflow()) {
+            // SAFETY: No allocations are performed while the iterator is alive,
+            // and the allocated memory is about to be freed.
+            for (ptr, len) in unsafe { bump.iter_allocated_chunks_raw() } {
+                unsafe { ptr.write_bytes(POISON_BYTE, len) }
+            }
+        }
+    }
+
+    // Releases the pages of the free space in the current chunk.
+    #[cfg(feature = "release_to_os")]
+    fn release_unused(&self) {
+        let bump = self.as_inner();
+        // SAFETY: No allocations are performed while the iterator is alive.
+        let Some((ptr, _)) = unsafe { bump.iter_allocated_chunks_raw() }.next() else {
+            return;
+        };
+
+        // The allocator bumps downwards, the free space ends at the current pointer.
+        let free = bump.chunk_capacity();
+        os::release_pages(ptr.wrapping_sub(free), free);
+    }
+
+    // Bytes reserved by the allocator's chunks but not handed out.
+    fn wasted_bytes(&self) -> usize {
+        let bump = self.as_inner();
+        // SAFETY: No allocations are performed while the iterator is alive.
+        let used: usize = unsafe { bump.iter_allocated_chunks_raw() }
+            .map(|(_, len)| len)
+            .sum();
+        bump.allocated_bytes().saturating_sub(used)
     }
 
-    /// Resets the allocator, deallocating all previously allocated memory.
-    ///
-    /// # Note
-    ///
-    /// - This does not run any `Drop` implementations.
-    /// - Like [`bumpalo::Bump::reset()`], callers must ensure no references to allocated memory
-    ///   are used after calling this method.
+    // Bytes consumed from the allocator's chunks.
     #[inline]
-    pub fn reset(&self) {
-        // SAFETY: ThreadLocal ensures single-thread access to this BumpLocal.
-        unsafe {
-            (*self.inner.get()).as_mut().unwrap().inner.reset();
-        }
+    fn allocated_bytes(&self) -> usize {
+        used_bytes(self.as_inner())
+    }
+
+    #[inline]
+    fn get(&self) -> Option<&BumpLocalInner> {
+        // SAFETY: The caller either owns this BumpLocal's thread or holds an `ExclusiveGuard`.
+        unsafe { (*self.inner.get()).as_ref() }
     }
 
     #[inline]
@@ -255,23 +2829,35 @@ impl BumpLocal {
     }
 
     #[cold]
-    fn init(&self, capacity: usize, limit: Option<usize>, thread_alive: Arc<AtomicBool>) {
-        let bump = bumpalo::Bump::with_capacity(capacity);
-        bump.set_allocation_limit(limit);
+    fn init(&self, inner: BumpLocalInner) {
+        // SAFETY: ThreadLocal ensures single-thread access to this BumpLocal.
+        unsafe { *self.inner.get() = Some(inner) }
+    }
 
+    #[cold]
+    fn rebuild(&self, config: BumpConfig) {
         // SAFETY: ThreadLocal ensures single-thread access to this BumpLocal.
-        unsafe {
-            *self.inner.get() = Some(BumpLocalInner {
-                inner: bump,
-                thread_alive,
-            })
+        if let Some(inner) = unsafe { (*self.inner.get()).as_mut() } {
+            inner.inner = new_bump(config.capacity, config.alloc_limit);
+            if config.prefault {
+                prefault(&inner.inner);
+            }
+            inner.overflow = config.overflow_limit.map(new_overflow);
+            inner.base_capacity = config.capacity;
+            inner.peak_bytes.set(0);
+            inner.chunk_bytes.set(inner.inner.allocated_bytes());
         }
     }
 
+    #[inline]
+    fn is_thread_alive(&self) -> bool {
+        self.get().is_some_and(BumpLocalInner::is_thread_alive)
+    }
+
     #[cold]
-    fn clear(&mut self) {
+    fn clear(&self) {
         #[cold]
-        fn drop_inner(bump: &mut BumpLocal) {
+        fn drop_inner(bump: &BumpLocal) {
             // SAFETY: ThreadLocal ensures single-thread access to this BumpLocal.
             unsafe {
                 let _ = (*bump.inner.get()).take();
@@ -284,7 +2870,7 @@ impl BumpLocal {
             return;
         };
 
-        if inner.thread_alive.load(Ordering::Acquire) {
+        if inner.is_thread_alive() {
             self.reset();
         } else {
             drop_inner(self);
@@ -294,43 +2880,592 @@ impl BumpLocal {
 
 struct BumpLocalInner {
     inner: bumpalo::Bump,
-    thread_alive: Arc<AtomicBool>,
+    // See `BumpBuilder::overflow_capacity`.
+    overflow: Option<bumpalo::Bump>,
+    // `None` if liveness tracking is disabled.
+    thread_alive: Option<Arc<AtomicBool>>,
+    thread_id: ThreadId,
+    // The capacity the allocator was created with, see `BumpLocal::base_capacity`.
+    base_capacity: usize,
+    peak_bytes: Cell<usize>,
+    // See `BumpBuilder::min_alignment`.
+    min_align: usize,
+    // See `BumpBuilder::on_chunk_grow`.
+    on_chunk_grow: Option<ChunkGrowFn>,
+    // See `BumpBuilder::panic_on_growth`.
+    panic_on_growth: bool,
+    // Bytes of the chunks at the last allocation, to detect growth.
+    chunk_bytes: Cell<usize>,
+    #[cfg(feature = "timing")]
+    init_time: Duration,
 }
 
-// Shared `Bump` state.
-#[derive(Default)]
-struct BumpInner {
-    locals: ThreadLocal<BumpLocal>,
+impl BumpLocalInner {
+    // Creates the allocator with `create`, which gets the capacity and the allocation limit.
+    fn new<E>(
+        config: BumpConfig,
+        min_align: usize,
+        on_chunk_grow: Option<ChunkGrowFn>,
+        thread_alive: Option<Arc<AtomicBool>>,
+        create: impl FnOnce(usize, Option<usize>) -> Result<bumpalo::Bump, E>,
+    ) -> Result<Self, E> {
+        #[cfg(feature = "timing")]
+        let start = Instant::now();
+
+        let inner = create(config.capacity, config.alloc_limit)?;
+        if config.prefault {
+            prefault(&inner);
+        }
+        Ok(Self {
+            chunk_bytes: Cell::new(inner.allocated_bytes()),
+            inner,
+            overflow: config.overflow_limit.map(new_overflow),
+            min_align,
+            on_chunk_grow,
+            panic_on_growth: config.panic_on_growth,
+            thread_alive,
+            thread_id: thread::current().id(),
+            base_capacity: config.capacity,
+            peak_bytes: Cell::new(0),
+            #[cfg(feature = "timing")]
+            init_time: start.elapsed(),
+        })
+    }
+
+    // Untracked threads are always considered alive.
+    #[inline]
+    fn is_thread_alive(&self) -> bool {
+        self.thread_alive
+            .as_ref()
+            .map_or(true, |alive| alive.load(Ordering::Acquire))
+    }
+}
+
+// Writes to every page of the free space in the current chunk.
+fn prefault(bump: &bumpalo::Bump) {
+    const PAGE_SIZE: usize = 4096;
+
+    // SAFETY: No allocations are performed while the iterator is alive.
+    let Some((top, _)) = unsafe { bump.iter_allocated_chunks_raw() }.next() else {
+        return;
+    };
+    let free = bump.chunk_capacity();
+    let start = top.wrapping_sub(free);
+    for offset in (0..free).step_by(PAGE_SIZE) {
+        // SAFETY: The free space belongs to the chunk and isn't handed out yet.
+        unsafe { start.add(offset).write_volatile(0) };
+    }
+}
+
+fn new_bump(capacity: usize, limit: Option<usize>) -> bumpalo::Bump {
+    let bump = bumpalo::Bump::with_capacity(capacity);
+    bump.set_allocation_limit(limit);
+    bump
+}
+
+// Like `new_bump`, but fails instead of aborting, also if the capacity exceeds the limit.
+fn try_new_bump(capacity: usize, limit: Option<usize>) -> Result<bumpalo::Bump, AllocError> {
+    if limit.is_some_and(|limit| capacity > limit) {
+        return Err(AllocError);
+    }
+    let bump = bumpalo::Bump::try_with_capacity(capacity).map_err(|_| AllocError)?;
+    bump.set_allocation_limit(limit);
+    Ok(bump)
+}
+
+// Bytes consumed from the allocator's chunks.
+#[inline]
+fn used_bytes(bump: &bumpalo::Bump) -> usize {
+    bump.allocated_bytes().saturating_sub(bump.chunk_capacity())
+}
+
+// The overflow allocator only allocates a chunk once it's used.
+fn new_overflow(limit: usize) -> bumpalo::Bump {
+    new_bump(0, Some(limit))
+}
+
+// `ThreadLocal` entry.
+//
+// `BumpLocal` is `!Sync`, which keeps references returned by `Bump::local` on their thread.
+// The slot itself is `Sync` so that an `ExclusiveGuard` holder can iterate every thread's
+// entry through a shared reference.
+struct LocalSlot {
+    local: BumpLocal,
+    // Generation of the last reset, see `Bump::try_reset_all`.
+    generation: AtomicUsize,
+}
+
+// SAFETY: Entries of other threads are only accessed through `ExclusiveGuard`,
+// which guarantees that no other thread can access the `Bump` concurrently.
+// The exception is the atomic generation.
+unsafe impl Sync for LocalSlot {}
+
+impl LocalSlot {
+    #[inline]
+    fn is_pending(&self, generation: usize) -> bool {
+        self.generation.load(Ordering::Acquire) != generation
+    }
+
+    #[inline]
+    fn set_generation(&self, generation: usize) {
+        self.generation.store(generation, Ordering::Release);
+    }
+}
+
+impl Deref for LocalSlot {
+    type Target = BumpLocal;
+
+    #[inline]
+    fn deref(&self) -> &BumpLocal {
+        &self.local
+    }
+}
+
+// Configuration of newly created allocators, see `Bump::reconfigure`.
+#[derive(Clone, Copy)]
+struct BumpConfig {
     capacity: usize,
     alloc_limit: Option<usize>,
+    overflow_limit: Option<usize>,
+    // See `BumpBuilder::prefault`.
+    prefault: bool,
+    // See `BumpBuilder::panic_on_growth`.
+    panic_on_growth: bool,
+}
+
+// Shared `Bump` state.
+struct BumpInner {
+    locals: ThreadLocal<LocalSlot>,
+    threads_capacity: Option<usize>,
+    config: Mutex<BumpConfig>,
+    retain_dead_arenas: bool,
+    lazy_reset_mode: LazyResetMode,
+    // See `BumpBuilder::reset_to_chunk_size`.
+    reset_chunk_size: Option<usize>,
+    // Bytes of an allocator rebuilt with `reset_chunk_size`, zero until the first rebuild.
+    reset_chunk_bytes: AtomicUsize,
+    // See `BumpBuilder::rebalance_on_reset`.
+    rebalance_on_reset: bool,
+    liveness_disabled: bool,
+    // See `BumpBuilder::poison_on_reset`.
+    poison_on_reset: bool,
+    min_align: usize,
+    on_chunk_grow: Option<ChunkGrowFn>,
+    // See `BumpBuilder::metrics_sink`.
+    metrics_sink: Option<MetricsSinkFn>,
+    #[cfg(any(feature = "allocator_api", feature = "allocator-api2"))]
+    count_allocations: bool,
+    // Successful `Allocator` calls, see `BumpBuilder::count_allocations`.
+    #[cfg(any(feature = "allocator_api", feature = "allocator-api2"))]
+    allocations: AtomicUsize,
+    #[cfg(feature = "release_to_os")]
+    release_to_os: bool,
+    // Set while a handle holds an `ExclusiveGuard`.
+    exclusive: AtomicBool,
+    // Incremented by `Bump::try_reset_all`.
+    generation: AtomicUsize,
+    // Incremented by every reset of other threads' allocators, see `SendRef`.
+    epoch: AtomicUsize,
+    // Callbacks registered with `Bump::on_before_reset`.
+    before_reset: Mutex<Vec<Box<dyn FnOnce() + Send>>>,
+    // Unique among all `BumpInner`s, unlike their addresses.
+    id: usize,
 }
 
 impl BumpInner {
     #[inline]
     fn local(&self) -> &BumpLocal {
-        let bump = self.locals.get_or(|| {
-            let thread_alive = THREAD_GUARD.with(|guard| guard.alive.clone());
-            BumpLocal::new(self.capacity, self.alloc_limit, thread_alive)
+        let slot = self.slot();
+
+        if slot.needs_init() {
+            self.reinit_local(slot);
+        } else if slot.generation.load(Ordering::Relaxed) != self.generation.load(Ordering::Relaxed)
+        {
+            self.catch_up(slot);
+        }
+
+        slot
+    }
+
+    // Like `local`, but fails instead of aborting if the allocator can't be created.
+    fn try_local(&self) -> Result<&BumpLocal, AllocError> {
+        let slot = match self.cached_slot() {
+            Some(slot) => slot,
+            None => {
+                let slot = self.locals.get_or_try(|| {
+                    Ok::<_, AllocError>(LocalSlot {
+                        local: BumpLocal::new(self.try_new_local_inner()?),
+                        generation: AtomicUsize::new(self.generation.load(Ordering::Relaxed)),
+                    })
+                })?;
+                self.cache_slot(slot);
+                slot
+            }
+        };
+
+        if slot.needs_init() {
+            slot.init(self.try_new_local_inner()?);
+            slot.set_generation(self.generation.load(Ordering::Relaxed));
+        } else if slot.generation.load(Ordering::Relaxed) != self.generation.load(Ordering::Relaxed)
+        {
+            self.catch_up(slot);
+        }
+
+        Ok(slot)
+    }
+
+    // Returns the current thread's slot, skipping the `ThreadLocal` lookup
+    // if this is the `Bump` the thread accessed last.
+    #[inline]
+    fn slot(&self) -> &LocalSlot {
+        if let Some(slot) = self.cached_slot() {
+            return slot;
+        }
+
+        let slot = self.locals.get_or(|| LocalSlot {
+            local: BumpLocal::new(self.new_local_inner()),
+            generation: AtomicUsize::new(self.generation.load(Ordering::Relaxed)),
         });
+        self.cache_slot(slot);
+        slot
+    }
+
+    #[inline]
+    fn cached_slot(&self) -> Option<&LocalSlot> {
+        #[cfg(not(loom))]
+        {
+            let (id, slot) = LAST_SLOT.with(Cell::get);
+            if id == self.id {
+                // SAFETY: The slot was cached by this thread for this `BumpInner`,
+                // whose entries are neither moved nor freed while it's alive.
+                return Some(unsafe { &*slot });
+            }
+        }
+        None
+    }
 
-        if bump.needs_init() {
-            self.reinit_local(bump);
+    #[inline]
+    fn cache_slot(&self, slot: &LocalSlot) {
+        #[cfg(not(loom))]
+        if LAST_SLOT_GUARD.try_with(|_| ()).is_ok() {
+            LAST_SLOT.with(|last| last.set((self.id, slot)));
+        }
+        #[cfg(loom)]
+        let _ = slot;
+    }
+
+    #[cold]
+    fn reinit_local(&self, slot: &LocalSlot) {
+        slot.init(self.new_local_inner());
+        slot.set_generation(self.generation.load(Ordering::Relaxed));
+    }
+
+    fn new_local_inner(&self) -> BumpLocalInner {
+        match self
+            .new_local_inner_with(|capacity, limit| Ok::<_, Infallible>(new_bump(capacity, limit)))
+        {
+            Ok(inner) => inner,
+            Err(never) => match never {},
         }
+    }
 
-        bump
+    fn try_new_local_inner(&self) -> Result<BumpLocalInner, AllocError> {
+        self.new_local_inner_with(try_new_bump)
     }
 
+    fn new_local_inner_with<E>(
+        &self,
+        create: impl FnOnce(usize, Option<usize>) -> Result<bumpalo::Bump, E>,
+    ) -> Result<BumpLocalInner, E> {
+        #[cfg(feature = "profiling")]
+        profiling::scope!("bump_local::init_local");
+
+        let thread_alive = self.thread_alive();
+        BumpLocalInner::new(
+            self.config(),
+            self.min_align,
+            self.on_chunk_grow.clone(),
+            thread_alive,
+            create,
+        )
+    }
+
+    // Performs a reset requested by `Bump::try_reset_all`.
     #[cold]
-    fn reinit_local(&self, bump: &BumpLocal) {
-        let thread_alive = THREAD_GUARD.with(|guard| guard.alive.clone());
-        bump.init(self.capacity, self.alloc_limit, thread_alive);
+    fn catch_up(&self, slot: &LocalSlot) {
+        let generation = self.generation.load(Ordering::Relaxed);
+        #[cfg(debug_assertions)]
+        if self.poison_on_reset {
+            slot.poison();
+        }
+        match self.lazy_reset_mode {
+            LazyResetMode::Reset => slot.reset(),
+            LazyResetMode::Reinit => slot.rebuild(self.config()),
+        }
+        slot.set_generation(generation);
+    }
+
+    fn config(&self) -> BumpConfig {
+        *self.lock_config()
+    }
+
+    fn lock_config(&self) -> MutexGuard<'_, BumpConfig> {
+        self.config.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    fn lock_before_reset(&self) -> MutexGuard<'_, Vec<Box<dyn FnOnce() + Send>>> {
+        self.before_reset
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+    }
+
+    #[inline]
+    fn thread_alive(&self) -> Option<Arc<AtomicBool>> {
+        (!self.liveness_disabled).then(current_thread_alive)
+    }
+
+    #[inline]
+    fn reset_all(&self) {
+        #[cfg(feature = "profiling")]
+        profiling::scope!("bump_local::reset_all");
+
+        self.begin_reset_all();
+        for slot in self.locals.iter() {
+            self.reset_local(slot);
+        }
+    }
+
+    // Like `reset_all`, measuring how long each thread's allocator takes to reset.
+    #[cfg(feature = "timing")]
+    fn reset_all_timed(&self) -> Vec<(ThreadId, Duration)> {
+        #[cfg(feature = "profiling")]
+        profiling::scope!("bump_local::reset_all");
+
+        self.begin_reset_all();
+        let mut durations = Vec::new();
+        for slot in self.locals.iter() {
+            let thread_id = slot.get().map(|inner| inner.thread_id);
+            let start = Instant::now();
+            self.reset_local(slot);
+            if let Some(thread_id) = thread_id {
+                durations.push((thread_id, start.elapsed()));
+            }
+        }
+        durations
+    }
+
+    fn begin_reset_all(&self) {
+        // Taken out first, callbacks may register new ones for the next reset.
+        let callbacks = mem::take(&mut *self.lock_before_reset());
+        for callback in callbacks {
+            callback();
+        }
+
+        self.epoch.fetch_add(1, Ordering::Release);
+    }
+
+    fn reset_where(&self, pred: impl Fn(ThreadId) -> bool) {
+        self.epoch.fetch_add(1, Ordering::Release);
+        for slot in self.locals.iter() {
+            if slot.get().is_some_and(|inner| pred(inner.thread_id)) {
+                self.reset_local(slot);
+            }
+        }
+    }
+
+    fn checkpoint_all(&self) -> Result<ArenaSnapshot, SnapshotError> {
+        let mut threads = Vec::new();
+        for local in self.locals.iter() {
+            let Some(inner) = local.get() else {
+                continue;
+            };
+            let Some((ptr, end)) = local.current_chunk() else {
+                continue;
+            };
+            // SAFETY: No allocations are performed while the iterator is alive.
+            let mut older = unsafe { inner.inner.iter_allocated_chunks_raw() }.skip(1);
+            let overflowed = local
+                .overflow()
+                .is_some_and(|overflow| used_bytes(overflow) > 0);
+            if older.any(|(_, len)| len > 0) || overflowed {
+                return Err(SnapshotError::Fragmented);
+            }
+            if ptr == end {
+                continue;
+            }
+
+            // SAFETY: The range is allocated from a chunk, and no handle
+            // can allocate or reset while the caller holds `ExclusiveGuard`.
+            let bytes = unsafe {
+                std::slice::from_raw_parts(
+                    ptr.cast::<MaybeUninit<u8>>(),
+                    end as usize - ptr as usize,
+                )
+            };
+            threads.push(ThreadSnapshot {
+                thread_id: inner.thread_id,
+                chunk_end: end as usize,
+                bytes: Box::from(bytes),
+            });
+        }
+        Ok(ArenaSnapshot { threads })
+    }
+
+    fn restore_all(&self, snapshot: &ArenaSnapshot) -> Result<(), SnapshotError> {
+        let find = |thread_id| {
+            self.locals
+                .iter()
+                .find(|slot| slot.get().is_some_and(|inner| inner.thread_id == thread_id))
+        };
+        // Checked up front, so nothing is reset if any thread can't be restored.
+        // A new chunk may end at the same address, but then it has to be large enough too.
+        for thread in &snapshot.threads {
+            let fits = find(thread.thread_id).is_some_and(|slot| {
+                slot.current_chunk().is_some_and(|(ptr, end)| {
+                    let size = slot.as_inner().chunk_capacity() + (end as usize - ptr as usize);
+                    end as usize == thread.chunk_end && size >= thread.bytes.len()
+                })
+            });
+            if !fits {
+                return Err(SnapshotError::ChunkReplaced);
+            }
+        }
+
+        self.epoch.fetch_add(1, Ordering::Release);
+        let generation = self.generation.load(Ordering::Relaxed);
+        for slot in self.locals.iter() {
+            if !slot.needs_init() {
+                slot.reset();
+            }
+            slot.set_generation(generation);
+        }
+        for thread in &snapshot.threads {
+            let local = find(thread.thread_id).expect("checked above");
+            let layout = Layout::array::<u8>(thread.bytes.len()).expect("allocation size overflow");
+            // A reset keeps the current chunk, so the bytes land where they were copied from.
+            let ptr = local.as_inner().alloc_layout(layout);
+            debug_assert_eq!(ptr.as_ptr() as usize + layout.size(), thread.chunk_end);
+            // SAFETY: `ptr` is a new allocation of the snapshot's length.
+            unsafe {
+                std::ptr::copy_nonoverlapping(
+                    thread.bytes.as_ptr(),
+                    ptr.as_ptr().cast(),
+                    layout.size(),
+                );
+            }
+            local.record_alloc();
+        }
+        Ok(())
     }
 
     #[inline]
-    fn reset_all(&mut self) {
-        for local in self.locals.iter_mut() {
+    fn reset_local(&self, slot: &LocalSlot) {
+        let local = &slot.local;
+        // Read before the reset clears it.
+        let peak = if self.rebalance_on_reset && !local.needs_init() {
+            local.peak_allocated_bytes()
+        } else {
+            0
+        };
+        #[cfg(debug_assertions)]
+        if self.poison_on_reset && !local.needs_init() {
+            local.poison();
+        }
+        if !self.retain_dead_arenas {
             local.clear();
+        } else if !local.needs_init() {
+            local.reset();
+        }
+        if let Some(size) = self.reset_chunk_size {
+            if !local.needs_init() {
+                self.resize_chunk(local, size);
+            }
+        } else if self.rebalance_on_reset && !local.needs_init() {
+            self.rebalance(local, peak);
+        }
+        slot.set_generation(self.generation.load(Ordering::Relaxed));
+
+        #[cfg(feature = "release_to_os")]
+        if self.release_to_os && !local.needs_init() {
+            local.release_unused();
+        }
+    }
+
+    // Replaces a reset allocator unless its chunk already has the size of a rebuilt one.
+    fn resize_chunk(&self, local: &BumpLocal, size: usize) {
+        let bytes = local.as_inner().allocated_bytes();
+        if bytes != self.reset_chunk_bytes.load(Ordering::Relaxed) {
+            let config = BumpConfig {
+                capacity: size,
+                ..self.config()
+            };
+            local.rebuild(config);
+            let bytes = local.as_inner().allocated_bytes();
+            self.reset_chunk_bytes.store(bytes, Ordering::Relaxed);
+        }
+    }
+
+    // Replaces a reset allocator whose chunk is too small or too large for `peak`.
+    //
+    // The slack keeps allocators with a steady peak from being rebuilt in every cycle,
+    // bumpalo rounds capacities up to twice the request.
+    fn rebalance(&self, local: &BumpLocal, peak: usize) {
+        let capacity = peak.max(MIN_REBALANCE_CAPACITY);
+        let bytes = local.as_inner().allocated_bytes();
+        if bytes < peak || bytes / 4 > capacity {
+            let config = BumpConfig {
+                capacity,
+                ..self.config()
+            };
+            local.rebuild(config);
+        }
+    }
+
+    #[inline]
+    fn clear_all(&self) {
+        self.epoch.fetch_add(1, Ordering::Release);
+        let config = self.config();
+        let generation = self.generation.load(Ordering::Relaxed);
+        for slot in self.locals.iter() {
+            if self.retain_dead_arenas || slot.is_thread_alive() {
+                slot.rebuild(config);
+            } else {
+                slot.clear();
+            }
+            slot.set_generation(generation);
+        }
+    }
+
+    fn compact(&self) {
+        let config = self.config();
+        for slot in self.locals.iter() {
+            let overflowed = slot
+                .overflow()
+                .is_some_and(|overflow| used_bytes(overflow) > 0);
+            if slot.is_thread_alive() && slot.allocated_bytes() == 0 && !overflowed {
+                slot.rebuild(config);
+            }
+        }
+    }
+
+    fn reset_stats(&self) -> ResetStats {
+        let mut stats = ResetStats::default();
+        for local in self.locals.iter() {
+            let Some(inner) = local.get() else {
+                continue;
+            };
+
+            if self.retain_dead_arenas || inner.is_thread_alive() {
+                stats.threads_reset += 1;
+            } else {
+                stats.threads_dropped += 1;
+            }
+            stats.allocated_bytes += local.allocated_bytes();
+            stats.capacity_bytes += inner.inner.allocated_bytes();
+            stats.overflow_bytes += local.overflow().map_or(0, used_bytes);
+            stats.peak_bytes = stats.peak_bytes.max(local.peak_allocated_bytes());
         }
+        stats
     }
 }
 
@@ -404,4 +3539,118 @@ mod tests {
         let local = locals.first().unwrap();
         assert!(local.needs_init());
     }
+
+    #[test]
+    fn reset_drops_dead_thread_bump_guard_initialized_first() {
+        let mut bump = Bump::builder().bump_capacity(100).build();
+
+        // Initializes the guard before the `ThreadLocal` entry, so its destructor
+        // may run after the entry's thread id is released.
+        let handle = {
+            let bump = bump.clone();
+            thread::spawn(move || {
+                let alive = current_thread_alive();
+                let _ = bump.local().as_inner().alloc(1_u8);
+                alive
+            })
+        };
+
+        let alive = handle.join().unwrap();
+        assert!(!alive.load(Ordering::Acquire));
+
+        bump.reset_all().unwrap();
+
+        let inner = Arc::get_mut(&mut bump.inner).unwrap();
+        let locals: Vec<_> = inner.locals.iter_mut().collect();
+        assert_eq!(locals.len(), 1);
+        assert!(locals[0].needs_init());
+    }
+
+    #[test]
+    fn exited_threads_entries_are_reused() {
+        let mut bump = Bump::builder().bump_capacity(100).build();
+        for _ in 0..4 {
+            let bump = bump.clone();
+            thread::spawn(move || {
+                let _ = bump.local().as_inner().alloc(1_u8);
+            })
+            .join()
+            .unwrap();
+        }
+
+        let inner = Arc::get_mut(&mut bump.inner).unwrap();
+        assert_eq!(inner.locals.iter_mut().count(), 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "call Bump::local() first")]
+    fn as_inner_uninitialized_panics() {
+        let mut bump = Bump::new();
+        let handle = {
+            let bump = bump.clone();
+            thread::spawn(move || {
+                bump.local();
+            })
+        };
+        handle.join().unwrap();
+        bump.reset_all().unwrap();
+
+        let inner = Arc::get_mut(&mut bump.inner).unwrap();
+        let local = inner.locals.iter_mut().next().unwrap();
+        assert!(local.needs_init());
+        local.as_inner();
+    }
+
+    #[test]
+    fn reset_retains_dead_thread_bump() {
+        let mut bump = Bump::builder()
+            .bump_capacity(100)
+            .retain_dead_arenas(true)
+            .build();
+
+        let handle = {
+            let bump = bump.clone();
+            thread::spawn(move || {
+                let _ = bump.local().as_inner().alloc(1_u8);
+            })
+        };
+
+        handle.join().unwrap();
+
+        bump.reset_all().unwrap();
+
+        let inner = Arc::get_mut(&mut bump.inner).unwrap();
+        let locals: Vec<_> = inner.locals.iter_mut().collect();
+        assert_eq!(locals.len(), 1);
+        let local = locals.first().unwrap();
+        assert!(!local.needs_init());
+        assert_eq!(local.allocated_bytes(), 0);
+    }
+
+    #[test]
+    fn reset_resets_untracked_dead_thread_bump() {
+        let mut bump = Bump::builder()
+            .bump_capacity(100)
+            .disable_liveness_tracking()
+            .build();
+
+        let handle = {
+            let bump = bump.clone();
+            thread::spawn(move || {
+                let _ = bump.local().as_inner().alloc(1_u8);
+            })
+        };
+
+        handle.join().unwrap();
+
+        bump.reset_all().unwrap();
+
+        let inner = Arc::get_mut(&mut bump.inner).unwrap();
+        let locals: Vec<_> = inner.locals.iter_mut().collect();
+        assert_eq!(locals.len(), 1);
+        let local = locals.first().unwrap();
+        assert!(local.get().unwrap().thread_alive.is_none());
+        assert!(!local.needs_init());
+        assert_eq!(local.allocated_bytes(), 0);
+    }
 }
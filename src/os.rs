@@ -0,0 +1,30 @@
+//! Returning unused arena memory to the operating system.
+
+/// Tells the OS that the pages within `ptr..ptr + len` are no longer needed.
+///
+/// Only whole pages are released. The memory stays valid, its contents become unspecified.
+#[cfg(all(unix, not(miri)))]
+pub(crate) fn release_pages(ptr: *mut u8, len: usize) {
+    // SAFETY: `sysconf` has no preconditions.
+    let page_size = match unsafe { libc::sysconf(libc::_SC_PAGESIZE) } {
+        size if size > 0 => size as usize,
+        _ => return,
+    };
+
+    let start = (ptr as usize + page_size - 1) / page_size * page_size;
+    let end = (ptr as usize + len) / page_size * page_size;
+    if start >= end {
+        return;
+    }
+
+    // SAFETY: The range lies within memory owned by the caller, which doesn't need its contents.
+    // The pages stay mapped. Linux zero-fills them on the next access, other systems
+    // may keep the old contents, so callers must not rely on either.
+    unsafe {
+        libc::madvise(start as *mut libc::c_void, end - start, libc::MADV_DONTNEED);
+    }
+}
+
+/// No-op on platforms without `madvise`.
+#[cfg(not(all(unix, not(miri))))]
+pub(crate) fn release_pages(_ptr: *mut u8, _len: usize) {}
@@ -0,0 +1,82 @@
+use crate::{Bump, ResetError};
+
+/// Resets a [`Bump`] allocator every `period` calls to [`maybe_reset`](Self::maybe_reset).
+///
+/// In a frame loop this keeps allocations alive for a few frames,
+/// so data from the previous frames can be reused without copying.
+///
+/// # Examples
+///
+/// ```
+/// use bump_local::{Bump, ResetScheduler};
+///
+/// let mut scheduler = ResetScheduler::new(Bump::new(), 3);
+///
+/// for frame in 0..6 {
+///     scheduler.bump().local().as_inner().alloc(frame);
+///
+///     let reset = scheduler.maybe_reset().unwrap();
+///     assert_eq!(reset, frame % 3 == 2);
+/// }
+/// ```
+pub struct ResetScheduler {
+    bump: Bump,
+    period: usize,
+    calls: usize,
+}
+
+impl ResetScheduler {
+    /// Creates a [`ResetScheduler`] that resets `bump` every `period` calls.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `period` is zero.
+    pub fn new(bump: Bump, period: usize) -> Self {
+        assert!(period > 0, "reset period must be non-zero");
+        Self {
+            bump,
+            period,
+            calls: 0,
+        }
+    }
+
+    /// Returns the scheduled allocator.
+    #[inline]
+    pub fn bump(&self) -> &Bump {
+        &self.bump
+    }
+
+    /// Returns the number of calls between resets.
+    #[inline]
+    pub fn period(&self) -> usize {
+        self.period
+    }
+
+    /// Counts a call and resets the allocator if it is the `period`-th one since the last reset.
+    ///
+    /// Returns whether the allocator was reset.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ResetError`] if the reset is due but other [`Bump`] handles exist.
+    /// The reset stays due, so the next call tries again.
+    ///
+    /// # Safety Contract
+    ///
+    /// Same as [`Bump::reset_all`].
+    pub fn maybe_reset(&mut self) -> Result<bool, ResetError> {
+        self.calls += 1;
+        if self.calls < self.period {
+            return Ok(false);
+        }
+
+        self.bump.reset_all()?;
+        self.calls = 0;
+        Ok(true)
+    }
+
+    /// Returns the scheduled allocator.
+    pub fn into_inner(self) -> Bump {
+        self.bump
+    }
+}
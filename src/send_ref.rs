@@ -0,0 +1,58 @@
+use std::{marker::PhantomData, ptr::NonNull, sync::atomic::Ordering};
+
+use crate::Bump;
+
+/// A value allocated in a [`Bump`] that can be sent to another thread.
+///
+/// Created with [`Bump::alloc_send`], turned back into a reference with [`into_ref`].
+/// The value is never dropped, like other arena allocations.
+///
+/// [`into_ref`]: Self::into_ref
+pub struct SendRef<T> {
+    ptr: NonNull<T>,
+    // `BumpInner::id` of the allocator the value lives in.
+    id: usize,
+    // `BumpInner::epoch` at the time of the allocation.
+    epoch: usize,
+    _marker: PhantomData<T>,
+}
+
+// SAFETY: The value is only accessible through `into_ref`, which consumes the `SendRef`,
+// so moving it to another thread moves the only access to the value.
+unsafe impl<T: Send> Send for SendRef<T> {}
+
+impl<T> SendRef<T> {
+    pub(crate) fn new(ptr: NonNull<T>, id: usize, epoch: usize) -> Self {
+        Self {
+            ptr,
+            id,
+            epoch,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Returns a reference to the value, borrowing `bump`.
+    ///
+    /// The borrow keeps `bump` from being reset while the reference is alive,
+    /// like references to values allocated through it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bump` isn't a handle to the allocator the value was allocated in,
+    /// or if any of its allocators was reset since.
+    pub fn into_ref(self, bump: &Bump) -> &T {
+        assert_eq!(
+            self.id, bump.inner.id,
+            "value was allocated in another Bump"
+        );
+        assert_eq!(
+            self.epoch,
+            bump.inner.epoch.load(Ordering::Acquire),
+            "Bump was reset after the value was allocated"
+        );
+
+        // SAFETY: The allocator is alive and wasn't reset, the caller of `Bump::alloc_send`
+        // guarantees it wasn't reset through `BumpLocal::reset` either.
+        unsafe { self.ptr.as_ref() }
+    }
+}
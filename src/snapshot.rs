@@ -0,0 +1,41 @@
+use std::{fmt, mem::MaybeUninit, thread::ThreadId};
+
+/// Copies of the allocated memory of every thread's allocator, taken by
+/// [`Bump::checkpoint_all`](crate::Bump::checkpoint_all).
+///
+/// [`Bump::restore_all`](crate::Bump::restore_all) writes the bytes back to the addresses
+/// they were copied from. The copies are plain bytes, so restoring only brings back values
+/// that don't point into memory that changed since, see `restore_all`.
+#[derive(Clone)]
+pub struct ArenaSnapshot {
+    pub(crate) threads: Vec<ThreadSnapshot>,
+}
+
+#[derive(Clone)]
+pub(crate) struct ThreadSnapshot {
+    pub(crate) thread_id: ThreadId,
+    // Address of the end of the chunk, the bytes are copied from just below it.
+    pub(crate) chunk_end: usize,
+    pub(crate) bytes: Box<[MaybeUninit<u8>]>,
+}
+
+impl ArenaSnapshot {
+    /// Returns the number of threads with allocations in the snapshot.
+    pub fn thread_count(&self) -> usize {
+        self.threads.len()
+    }
+
+    /// Returns the number of bytes copied from all threads.
+    pub fn bytes(&self) -> usize {
+        self.threads.iter().map(|thread| thread.bytes.len()).sum()
+    }
+}
+
+impl fmt::Debug for ArenaSnapshot {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ArenaSnapshot")
+            .field("threads", &self.thread_count())
+            .field("bytes", &self.bytes())
+            .finish()
+    }
+}
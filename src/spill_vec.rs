@@ -0,0 +1,226 @@
+use std::{
+    fmt,
+    mem::{self, ManuallyDrop, MaybeUninit},
+    ops::{Deref, DerefMut},
+    ptr::{self, NonNull},
+    slice,
+};
+
+use crate::Bump;
+
+/// A vector that keeps up to `N` items inline and spills into the [`Bump`] allocator on overflow.
+///
+/// Spilled storage is allocated in the allocator of the thread that grows the vector.
+/// Like other arena allocations, memory of outgrown buffers is only reclaimed on reset,
+/// but items are dropped when the vector is dropped.
+///
+/// Created with [`Bump::spill_vec`].
+///
+/// # Examples
+///
+/// ```
+/// use bump_local::Bump;
+///
+/// let bump = Bump::new();
+/// let mut tokens = bump.spill_vec::<u32, 8>();
+///
+/// tokens.extend(0..8);
+/// assert!(!tokens.is_spilled());
+///
+/// tokens.push(8);
+/// assert!(tokens.is_spilled());
+/// assert_eq!(tokens.len(), 9);
+/// ```
+pub struct SpillVec<'a, T, const N: usize> {
+    bump: &'a Bump,
+    len: usize,
+    storage: Storage<'a, T, N>,
+}
+
+enum Storage<'a, T, const N: usize> {
+    Inline([MaybeUninit<T>; N]),
+    Spilled(&'a mut [MaybeUninit<T>]),
+}
+
+impl<'a, T, const N: usize> SpillVec<'a, T, N> {
+    /// Creates an empty [`SpillVec`] that spills into `bump`.
+    pub fn new_in(bump: &'a Bump) -> Self {
+        Self {
+            bump,
+            len: 0,
+            // SAFETY: An array of `MaybeUninit` doesn't require initialization.
+            storage: Storage::Inline(unsafe { MaybeUninit::uninit().assume_init() }),
+        }
+    }
+
+    /// Returns the number of items.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the vector contains no items.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the number of items the vector can hold without reallocating.
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.buf().len()
+    }
+
+    /// Returns `true` if the items were moved into the arena.
+    #[inline]
+    pub fn is_spilled(&self) -> bool {
+        matches!(self.storage, Storage::Spilled(_))
+    }
+
+    /// Reserves capacity for at least `additional` more items.
+    pub fn reserve(&mut self, additional: usize) {
+        let required = self.len.checked_add(additional).expect("capacity overflow");
+        if required > self.capacity() {
+            self.grow(required);
+        }
+    }
+
+    /// Appends an item, spilling into the arena if the inline storage is full.
+    #[inline]
+    pub fn push(&mut self, value: T) {
+        if self.len == self.capacity() {
+            self.grow(self.len + 1);
+        }
+
+        let len = self.len;
+        self.buf_mut()[len].write(value);
+        self.len += 1;
+    }
+
+    /// Removes the last item and returns it, or `None` if the vector is empty.
+    #[inline]
+    pub fn pop(&mut self) -> Option<T> {
+        if self.len == 0 {
+            return None;
+        }
+
+        self.len -= 1;
+        let len = self.len;
+        // SAFETY: Items below the old length are initialized, and the length was decreased.
+        Some(unsafe { self.buf_mut()[len].assume_init_read() })
+    }
+
+    /// Drops all items, keeping the current storage.
+    pub fn clear(&mut self) {
+        let items: *mut [T] = self.as_mut_slice();
+        self.len = 0;
+        // SAFETY: The items are initialized and no longer reachable after setting the length.
+        unsafe { ptr::drop_in_place(items) };
+    }
+
+    /// Returns the items as a slice.
+    #[inline]
+    pub fn as_slice(&self) -> &[T] {
+        // SAFETY: Items below the length are initialized.
+        unsafe { slice::from_raw_parts(self.buf().as_ptr().cast(), self.len) }
+    }
+
+    /// Returns the items as a mutable slice.
+    #[inline]
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        let len = self.len;
+        // SAFETY: Items below the length are initialized.
+        unsafe { slice::from_raw_parts_mut(self.buf_mut().as_mut_ptr().cast(), len) }
+    }
+
+    #[inline]
+    fn buf(&self) -> &[MaybeUninit<T>] {
+        match &self.storage {
+            Storage::Inline(items) => items,
+            Storage::Spilled(items) => items,
+        }
+    }
+
+    #[inline]
+    fn buf_mut(&mut self) -> &mut [MaybeUninit<T>] {
+        match &mut self.storage {
+            Storage::Inline(items) => items,
+            Storage::Spilled(items) => items,
+        }
+    }
+
+    /// Consumes the vector and returns its items as a slice in the arena.
+    ///
+    /// Inline items are moved into the arena first. The items are no longer dropped.
+    pub fn into_slice(mut self) -> &'a mut [T] {
+        if !self.is_spilled() && !self.is_empty() {
+            self.grow(self.len);
+        }
+
+        // The items are handed over to the caller.
+        let this = ManuallyDrop::new(self);
+        // SAFETY: `this` is never used again.
+        let storage = unsafe { ptr::read(&this.storage) };
+
+        match storage {
+            Storage::Inline(_) => &mut [],
+            // SAFETY: Items below the length are initialized.
+            Storage::Spilled(items) => unsafe {
+                slice::from_raw_parts_mut(items.as_mut_ptr().cast(), this.len)
+            },
+        }
+    }
+
+    #[cold]
+    fn grow(&mut self, min_capacity: usize) {
+        let new_buf: &'a mut [MaybeUninit<T>] = if mem::size_of::<T>() == 0 {
+            // SAFETY: Any aligned pointer is valid for zero-sized types.
+            unsafe { slice::from_raw_parts_mut(NonNull::dangling().as_ptr(), usize::MAX) }
+        } else {
+            let capacity = self.capacity().saturating_mul(2).max(min_capacity).max(4);
+            self.bump.local().alloc_slice_uninit(capacity)
+        };
+
+        // SAFETY: The new buffer is larger than the old one and they don't overlap.
+        unsafe {
+            ptr::copy_nonoverlapping(self.buf().as_ptr(), new_buf.as_mut_ptr(), self.len);
+        }
+        self.storage = Storage::Spilled(new_buf);
+    }
+}
+
+impl<T, const N: usize> Drop for SpillVec<'_, T, N> {
+    fn drop(&mut self) {
+        self.clear();
+    }
+}
+
+impl<T, const N: usize> Deref for SpillVec<'_, T, N> {
+    type Target = [T];
+
+    #[inline]
+    fn deref(&self) -> &[T] {
+        self.as_slice()
+    }
+}
+
+impl<T, const N: usize> DerefMut for SpillVec<'_, T, N> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut [T] {
+        self.as_mut_slice()
+    }
+}
+
+impl<T, const N: usize> Extend<T> for SpillVec<'_, T, N> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for value in iter {
+            self.push(value);
+        }
+    }
+}
+
+impl<T: fmt::Debug, const N: usize> fmt::Debug for SpillVec<'_, T, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.as_slice()).finish()
+    }
+}
@@ -0,0 +1,141 @@
+use std::fmt;
+#[cfg(feature = "timing")]
+use std::{thread::ThreadId, time::Duration};
+
+/// Summary of what [`Bump::reset_all`](crate::Bump::reset_all) does across all threads.
+///
+/// The byte and thread counts are sums or maxima over threads, so they don't depend on
+/// the order in which threads are visited.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct ResetStats {
+    /// Number of live threads whose allocators are reset.
+    pub threads_reset: usize,
+    /// Number of exited threads whose allocators are dropped.
+    pub threads_dropped: usize,
+    /// Bytes allocated across all threads, including unused space at the end of
+    /// previously filled chunks.
+    pub allocated_bytes: usize,
+    /// Bytes reserved by chunks across all threads.
+    pub capacity_bytes: usize,
+    /// Highest [peak of allocated bytes](crate::BumpLocal::peak_allocated_bytes)
+    /// of a single thread since the previous reset.
+    pub peak_bytes: usize,
+    /// Bytes allocated across all threads' [overflow allocators], not included in
+    /// [`allocated_bytes`](Self::allocated_bytes).
+    ///
+    /// [overflow allocators]: crate::BumpBuilder::overflow_capacity
+    pub overflow_bytes: usize,
+    /// How long resetting each thread's allocator took, in unspecified order.
+    ///
+    /// Only filled in by [`Bump::reset_all_reporting`](crate::Bump::reset_all_reporting),
+    /// since the other methods returning statistics don't reset anything.
+    #[cfg(feature = "timing")]
+    pub per_thread_durations: Vec<(ThreadId, Duration)>,
+}
+
+impl ResetStats {
+    /// Checks the statistics against `thresholds`, returning which ones were exceeded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bump_local::{Bump, ResetThresholds};
+    ///
+    /// let thresholds = ResetThresholds::new()
+    ///     .allocated_bytes(64 << 20)
+    ///     .peak_bytes(16 << 20);
+    ///
+    /// let mut bump = Bump::new();
+    /// let stats = bump.reset_all_dry_run().unwrap();
+    /// if stats.exceeds(&thresholds).any() {
+    ///     bump.reset_all().unwrap();
+    /// }
+    /// ```
+    pub fn exceeds(&self, thresholds: &ResetThresholds) -> ExceededThresholds {
+        let exceeds = |value: usize, threshold: Option<usize>| threshold.is_some_and(|t| value > t);
+        ExceededThresholds {
+            allocated_bytes: exceeds(self.allocated_bytes, thresholds.allocated_bytes),
+            capacity_bytes: exceeds(self.capacity_bytes, thresholds.capacity_bytes),
+            peak_bytes: exceeds(self.peak_bytes, thresholds.peak_bytes),
+        }
+    }
+}
+
+/// Limits to check [`ResetStats`] against, see [`ResetStats::exceeds`].
+///
+/// Unset thresholds are never exceeded.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct ResetThresholds {
+    allocated_bytes: Option<usize>,
+    capacity_bytes: Option<usize>,
+    peak_bytes: Option<usize>,
+}
+
+impl ResetThresholds {
+    /// Creates [`ResetThresholds`] without any thresholds set.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the threshold for [`ResetStats::allocated_bytes`] across all threads.
+    #[must_use]
+    pub fn allocated_bytes(mut self, bytes: usize) -> Self {
+        self.allocated_bytes = Some(bytes);
+        self
+    }
+
+    /// Sets the threshold for [`ResetStats::capacity_bytes`] across all threads.
+    #[must_use]
+    pub fn capacity_bytes(mut self, bytes: usize) -> Self {
+        self.capacity_bytes = Some(bytes);
+        self
+    }
+
+    /// Sets the threshold for [`ResetStats::peak_bytes`], the peak of any single thread.
+    #[must_use]
+    pub fn peak_bytes(mut self, bytes: usize) -> Self {
+        self.peak_bytes = Some(bytes);
+        self
+    }
+}
+
+/// Which [`ResetThresholds`] were exceeded, returned by [`ResetStats::exceeds`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct ExceededThresholds {
+    /// The allocated bytes threshold was exceeded.
+    pub allocated_bytes: bool,
+    /// The capacity bytes threshold was exceeded.
+    pub capacity_bytes: bool,
+    /// The peak bytes threshold was exceeded.
+    pub peak_bytes: bool,
+}
+
+impl ExceededThresholds {
+    /// Returns `true` if any threshold was exceeded.
+    pub fn any(&self) -> bool {
+        self.allocated_bytes || self.capacity_bytes || self.peak_bytes
+    }
+}
+
+// Formats a byte count with a binary unit, e.g. `12.4 MiB`.
+pub(crate) struct ByteSize(pub(crate) usize);
+
+impl fmt::Display for ByteSize {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+
+        if self.0 < 1024 {
+            return write!(f, "{} B", self.0);
+        }
+
+        let mut value = self.0 as f64 / 1024.0;
+        let mut unit = 0;
+        while value >= 1024.0 && unit < UNITS.len() - 1 {
+            value /= 1024.0;
+            unit += 1;
+        }
+        write!(f, "{value:.1} {}", UNITS[unit])
+    }
+}
@@ -0,0 +1,193 @@
+//! Per-thread storage backing [`Bump`](crate::Bump).
+//!
+//! Under Miri the `thread_local` crate is replaced with a simpler storage keyed by `ThreadId`,
+//! which is much cheaper to interpret. Like `thread_local`, it hands the entry of an exited
+//! thread to the next new thread, so the list only grows with the number of threads alive
+//! at the same time. Looking up an entry that isn't the thread's cached one scans the list
+//! under a single lock. The same storage is used under `loom`, keyed by `loom`'s threads,
+//! with the `mutex-backend` feature, and on WASM without atomics, which is single-threaded.
+
+#[cfg(not(any(
+    miri,
+    loom,
+    feature = "mutex-backend",
+    all(target_family = "wasm", not(target_feature = "atomics"))
+)))]
+pub(crate) use thread_local::ThreadLocal;
+
+#[cfg(any(
+    miri,
+    loom,
+    feature = "mutex-backend",
+    all(target_family = "wasm", not(target_feature = "atomics"))
+))]
+pub(crate) use self::miri::ThreadLocal;
+
+#[cfg(any(
+    miri,
+    loom,
+    feature = "mutex-backend",
+    all(target_family = "wasm", not(target_feature = "atomics"))
+))]
+mod miri {
+    use std::{
+        ptr::NonNull,
+        sync::{Arc, PoisonError},
+    };
+
+    use crate::sync::{AtomicBool, atomic::Ordering};
+
+    #[cfg(not(loom))]
+    use std::{
+        sync::{Mutex, MutexGuard},
+        thread::{self, ThreadId},
+    };
+
+    #[cfg(loom)]
+    use loom::{
+        sync::{Mutex, MutexGuard},
+        thread::{self, ThreadId},
+    };
+
+    pub(crate) struct ThreadLocal<T: Send> {
+        // Entries are leaked boxes, so references stay valid while the vector grows.
+        entries: Mutex<Vec<Entry<T>>>,
+    }
+
+    struct Entry<T> {
+        owner: ThreadId,
+        // Cleared when the owner exits, see `crate::current_thread_alive`.
+        owner_alive: Arc<AtomicBool>,
+        ptr: NonNull<T>,
+    }
+
+    // SAFETY: Same guarantees as `thread_local::ThreadLocal`,
+    // each thread only gets a shared reference to its own entry.
+    unsafe impl<T: Send> Send for ThreadLocal<T> {}
+    unsafe impl<T: Send> Sync for ThreadLocal<T> {}
+
+    impl<T: Send> Default for ThreadLocal<T> {
+        fn default() -> Self {
+            Self::new()
+        }
+    }
+
+    impl<T: Send> ThreadLocal<T> {
+        pub(crate) fn new() -> Self {
+            Self::with_capacity(0)
+        }
+
+        pub(crate) fn with_capacity(capacity: usize) -> Self {
+            Self {
+                entries: Mutex::new(Vec::with_capacity(capacity)),
+            }
+        }
+
+        pub(crate) fn get_or<F>(&self, create: F) -> &T
+        where
+            F: FnOnce() -> T,
+        {
+            match self.get_or_try(|| Ok::<_, std::convert::Infallible>(create())) {
+                Ok(value) => value,
+                Err(never) => match never {},
+            }
+        }
+
+        pub(crate) fn get_or_try<F, E>(&self, create: F) -> Result<&T, E>
+        where
+            F: FnOnce() -> Result<T, E>,
+        {
+            let id = thread::current().id();
+            let mut entries = self.lock();
+            if let Some(entry) = entries.iter().find(|entry| entry.owner == id) {
+                // SAFETY: Entries are only freed on drop.
+                return Ok(unsafe { entry.ptr.as_ref() });
+            }
+
+            let owner_alive = owner_alive();
+            let ptr = match entries
+                .iter_mut()
+                .find(|entry| !entry.owner_alive.load(Ordering::Acquire))
+            {
+                // Taken over like `thread_local` does, the previous owner can't access it anymore.
+                Some(entry) => {
+                    entry.owner = id;
+                    entry.owner_alive = owner_alive;
+                    entry.ptr
+                }
+                None => {
+                    // Created without the lock, `create` may access other `ThreadLocal`s.
+                    drop(entries);
+                    let ptr = NonNull::from(Box::leak(Box::new(create()?)));
+                    self.lock().push(Entry {
+                        owner: id,
+                        owner_alive,
+                        ptr,
+                    });
+                    ptr
+                }
+            };
+
+            // SAFETY: Entries are only freed on drop.
+            Ok(unsafe { ptr.as_ref() })
+        }
+
+        pub(crate) fn get(&self) -> Option<&T> {
+            let id = thread::current().id();
+            let ptr = self
+                .lock()
+                .iter()
+                .find(|entry| entry.owner == id)
+                .map(|entry| entry.ptr)?;
+
+            // SAFETY: Entries are only freed on drop.
+            Some(unsafe { ptr.as_ref() })
+        }
+
+        pub(crate) fn iter(&self) -> impl Iterator<Item = &T>
+        where
+            T: Sync,
+        {
+            let ptrs: Vec<_> = self.lock().iter().map(|entry| entry.ptr).collect();
+
+            // SAFETY: Entries are only freed on drop.
+            ptrs.into_iter().map(|ptr| unsafe { ptr.as_ref() })
+        }
+
+        #[cfg(any(test, feature = "interner"))]
+        pub(crate) fn iter_mut(&mut self) -> impl Iterator<Item = &mut T> {
+            self.entries
+                .get_mut()
+                .unwrap_or_else(PoisonError::into_inner)
+                .iter_mut()
+                // SAFETY: `&mut self` guarantees no other references to entries exist.
+                .map(|entry| unsafe { entry.ptr.as_mut() })
+        }
+
+        fn lock(&self) -> MutexGuard<'_, Vec<Entry<T>>> {
+            self.entries.lock().unwrap_or_else(PoisonError::into_inner)
+        }
+    }
+
+    impl<T: Send> Drop for ThreadLocal<T> {
+        fn drop(&mut self) {
+            for entry in self
+                .entries
+                .get_mut()
+                .unwrap_or_else(PoisonError::into_inner)
+                .drain(..)
+            {
+                // SAFETY: The pointer came from `Box::leak` and is dropped only once.
+                drop(unsafe { Box::from_raw(entry.ptr.as_ptr()) });
+            }
+        }
+    }
+
+    // A thread running its thread-local destructors may not have a liveness flag anymore.
+    // Its entries then get one that is never cleared, and are only freed on drop.
+    fn owner_alive() -> Arc<AtomicBool> {
+        crate::THREAD_GUARD
+            .try_with(|guard| guard.alive.clone())
+            .unwrap_or_else(|_| Arc::new(AtomicBool::new(true)))
+    }
+}
@@ -0,0 +1,16 @@
+//! Atomics and thread-locals, replaced with `loom`'s when built with `--cfg loom`.
+//!
+//! Only the liveness flag protocol and the exclusive access flag are modeled.
+//! The `Arc` reference counts stay `std`'s, `loom::sync::Arc` has no `Weak`.
+
+#[cfg(not(loom))]
+pub(crate) use std::{
+    sync::atomic::{self, AtomicBool, AtomicUsize},
+    thread_local,
+};
+
+#[cfg(loom)]
+pub(crate) use loom::{
+    sync::atomic::{self, AtomicBool, AtomicUsize},
+    thread_local,
+};
@@ -0,0 +1,86 @@
+use crate::{Bump, SpillVec};
+
+/// Builds a slice in the [`Bump`] allocator when its final length isn't known up front.
+///
+/// Created with [`Bump::vec_builder`], finished with [`into_slice`](Self::into_slice).
+///
+/// # Examples
+///
+/// ```
+/// use bump_local::Bump;
+///
+/// let bump = Bump::new();
+/// let mut builder = bump.vec_builder();
+/// builder.extend((0..100).filter(|i| i % 3 == 0));
+/// builder.push(100);
+///
+/// let slice: &mut [i32] = builder.into_slice();
+/// assert_eq!(slice.len(), 35);
+/// ```
+pub struct ArenaVecBuilder<'a, T> {
+    vec: SpillVec<'a, T, 0>,
+}
+
+impl<'a, T> ArenaVecBuilder<'a, T> {
+    /// Creates an empty [`ArenaVecBuilder`] allocating in `bump`.
+    pub fn new_in(bump: &'a Bump) -> Self {
+        Self {
+            vec: SpillVec::new_in(bump),
+        }
+    }
+
+    /// Creates an empty [`ArenaVecBuilder`] with room for at least `capacity` items.
+    pub fn with_capacity_in(capacity: usize, bump: &'a Bump) -> Self {
+        let mut builder = Self::new_in(bump);
+        builder.reserve(capacity);
+        builder
+    }
+
+    /// Returns the number of items pushed so far.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.vec.len()
+    }
+
+    /// Returns `true` if no items were pushed.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.vec.is_empty()
+    }
+
+    /// Reserves capacity for at least `additional` more items.
+    ///
+    /// Growing moves the items to a new allocation; the old one is reclaimed on reset.
+    #[inline]
+    pub fn reserve(&mut self, additional: usize) {
+        self.vec.reserve(additional);
+    }
+
+    /// Appends an item.
+    #[inline]
+    pub fn push(&mut self, value: T) {
+        self.vec.push(value);
+    }
+
+    /// Returns the items pushed so far.
+    #[inline]
+    pub fn as_slice(&self) -> &[T] {
+        self.vec.as_slice()
+    }
+
+    /// Consumes the builder and returns the items as a slice tied to the allocator.
+    ///
+    /// Like other arena allocations, the items are not dropped.
+    #[inline]
+    pub fn into_slice(self) -> &'a mut [T] {
+        self.vec.into_slice()
+    }
+}
+
+impl<T> Extend<T> for ArenaVecBuilder<'_, T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        let iter = iter.into_iter();
+        self.reserve(iter.size_hint().0);
+        self.vec.extend(iter);
+    }
+}
@@ -0,0 +1,62 @@
+//! The `Allocator` implementation used through `allocator-api2` collections.
+#![cfg(all(feature = "allocator-api2", not(feature = "allocator_api")))]
+
+use std::alloc::Layout;
+
+use allocator_api2::{boxed::Box, vec::Vec};
+use bump_local::{Allocator, Bump};
+
+#[test]
+fn boxed() {
+    let bump = Bump::builder().bump_capacity(64).build();
+    let value = Box::new_in([7_u64; 16], bump.clone());
+    assert_eq!(*value, [7; 16]);
+}
+
+#[test]
+fn vec_grows_across_chunks_and_shrinks() {
+    let mut bump = Bump::builder().bump_capacity(64).build();
+    {
+        let mut values = Vec::new_in(&bump);
+        for i in 0..1000_u64 {
+            values.push(i);
+        }
+        assert!(bump.local().chunks_allocated_since_reset() > 0);
+        assert!(values.iter().copied().eq(0..1000));
+
+        values.truncate(10);
+        values.shrink_to_fit();
+        assert_eq!(values.capacity(), 10);
+        assert!(values.iter().copied().eq(0..10));
+    }
+    bump.reset_all().unwrap();
+}
+
+#[test]
+fn grow_zeroed() {
+    let bump = Bump::builder().bump_capacity(64).build();
+    let old = Layout::array::<u8>(16).unwrap();
+    let new = Layout::array::<u8>(256).unwrap();
+
+    let ptr = bump.allocate(old).unwrap().cast::<u8>();
+    // SAFETY: `ptr` was allocated with `old` and is written within its bounds.
+    unsafe { ptr.as_ptr().write_bytes(1, 16) };
+    // SAFETY: `ptr` was allocated by `bump` with `old`, and `new` is larger.
+    let grown = unsafe { bump.grow_zeroed(ptr, old, new) }.unwrap();
+
+    // SAFETY: `grow_zeroed` returns a block of at least `new.size()` initialized bytes.
+    let bytes = unsafe { std::slice::from_raw_parts(grown.cast::<u8>().as_ptr(), 256) };
+    assert_eq!(bytes[..16], [1; 16]);
+    assert!(bytes[16..].iter().all(|&byte| byte == 0));
+}
+
+#[test]
+fn allocate_over_aligned() {
+    let bump = Bump::builder().min_alignment(16).build();
+    let layout = Layout::from_size_align(24, 64).unwrap();
+    for _ in 0..4 {
+        let ptr = bump.allocate(layout).unwrap();
+        assert_eq!(ptr.cast::<u8>().as_ptr() as usize % 64, 0);
+        assert!(ptr.len() >= 24);
+    }
+}
@@ -4,7 +4,9 @@ use std::{
     thread::{self, JoinHandle},
 };
 
-use bump_local::Bump;
+use bump_local::{
+    ArenaVecBuilder, BuildError, Bump, Capacity, LazyResetMode, ResetScheduler, SnapshotError,
+};
 
 macro_rules! wg_new {
     ($count:expr) => {
@@ -151,3 +153,1269 @@ fn local_reuse() {
         handle.join().unwrap()
     }
 }
+
+#[test]
+fn local_alternating_bumps() {
+    let a = Bump::new();
+    let b = Bump::new();
+
+    let local_a = a.local() as *const _;
+    assert!(!std::ptr::eq(b.local(), local_a));
+    assert!(std::ptr::eq(a.local(), local_a));
+
+    // A new allocator never gets the cached entry of a dropped one (checked by Miri)
+    drop(a);
+    let c = Bump::new();
+    assert_eq!(*c.alloc(1), 1);
+}
+
+#[test]
+fn weak_does_not_block_reset() {
+    let mut bump = Bump::builder().bump_capacity(100).build();
+    let weak = bump.downgrade();
+
+    let handle = {
+        let weak = weak.clone();
+        thread::spawn(move || {
+            let bump = weak.upgrade().unwrap();
+            let _ = bump.local().as_inner().alloc(1_u8);
+        })
+    };
+    handle.join().unwrap();
+
+    bump.reset_all().unwrap();
+
+    let upgraded = weak.upgrade().unwrap();
+    assert!(bump.reset_all().is_err());
+    drop(upgraded);
+
+    drop(bump);
+    assert!(weak.upgrade().is_none());
+}
+
+#[test]
+fn reset_all_dry_run() {
+    let mut bump = Bump::builder().bump_capacity(100).build();
+    let _ = bump.local().as_inner().alloc([0_u8; 32]);
+
+    let handle = {
+        let bump = bump.clone();
+        thread::spawn(move || {
+            let _ = bump.local().as_inner().alloc(1_u64);
+        })
+    };
+    handle.join().unwrap();
+
+    let stats = bump.reset_all_dry_run().unwrap();
+    assert_eq!(stats.threads_reset, 1);
+    assert_eq!(stats.threads_dropped, 1);
+    assert!(stats.allocated_bytes >= 40);
+    assert!(stats.capacity_bytes >= 200);
+
+    // Nothing was reset
+    assert_eq!(bump.reset_all_dry_run().unwrap(), stats);
+
+    bump.reset_all().unwrap();
+    let stats = bump.reset_all_dry_run().unwrap();
+    assert_eq!(stats.threads_reset, 1);
+    assert_eq!(stats.threads_dropped, 0);
+    assert_eq!(stats.allocated_bytes, 0);
+
+    let _clone = bump.clone();
+    assert!(bump.reset_all_dry_run().is_err());
+}
+
+#[test]
+fn reset_where() {
+    let mut bump = Bump::builder().bump_capacity(100).build();
+    let _ = bump.local().as_inner().alloc([0_u8; 32]);
+
+    let handle = {
+        let bump = bump.clone();
+        thread::spawn(move || {
+            let _ = bump.local().as_inner().alloc(1_u64);
+        })
+    };
+    handle.join().unwrap();
+
+    let coordinator = thread::current().id();
+    bump.reset_where(|id| id != coordinator).unwrap();
+
+    let stats = bump.reset_all_dry_run().unwrap();
+    assert_eq!(stats.threads_reset, 1);
+    assert_eq!(stats.threads_dropped, 0);
+    assert!(stats.allocated_bytes >= 32);
+}
+
+#[test]
+fn total_wasted_bytes() {
+    let mut bump = Bump::builder().bump_capacity(1024).build();
+    let _ = bump.local().as_inner().alloc([0_u8; 256]);
+
+    let stats = bump.reset_all_dry_run().unwrap();
+    let wasted = bump.total_wasted_bytes().unwrap();
+    assert!(wasted > 0);
+    assert!(wasted <= stats.capacity_bytes - 256);
+
+    bump.reset_all().unwrap();
+    assert_eq!(bump.total_wasted_bytes().unwrap(), stats.capacity_bytes);
+}
+
+#[test]
+fn reset_scheduler_retries() {
+    let mut scheduler = ResetScheduler::new(Bump::new(), 2);
+    assert!(!scheduler.maybe_reset().unwrap());
+
+    let clone = scheduler.bump().clone();
+    assert!(scheduler.maybe_reset().is_err());
+
+    drop(clone);
+    assert!(scheduler.maybe_reset().unwrap());
+    assert!(!scheduler.maybe_reset().unwrap());
+}
+
+#[test]
+fn default_capacity() {
+    let bump = Bump::new();
+    assert!(bump.local().as_inner().chunk_capacity() >= 4096);
+
+    let empty = Bump::empty();
+    assert!(empty.local().as_inner().chunk_capacity() < 4096);
+}
+
+#[test]
+fn capacity_validation() {
+    assert_eq!(Capacity::new(0), Err(BuildError::ZeroCapacity));
+    assert_eq!(Capacity::new(usize::MAX), Err(BuildError::CapacityOverflow));
+    assert_eq!(Capacity::try_from(100).unwrap().get(), 100);
+    assert_eq!(Capacity::rounded_up(100).unwrap().get(), 128);
+    assert_eq!(Capacity::rounded_up(0), Err(BuildError::ZeroCapacity));
+    assert_eq!(
+        Capacity::rounded_up(isize::MAX as usize),
+        Err(BuildError::CapacityOverflow)
+    );
+}
+
+#[test]
+#[should_panic(expected = "invalid bump capacity")]
+fn zero_bump_capacity_panics() {
+    let _ = Bump::builder().bump_capacity(0);
+}
+
+#[test]
+fn alloc_str_concat() {
+    let bump = Bump::new();
+    assert_eq!(bump.alloc_str_concat(&[]), "");
+    assert_eq!(bump.alloc_str_concat(&["", "ß", "", "日本"]), "ß日本");
+}
+
+#[test]
+fn alloc_concat_slices() {
+    let bump = Bump::new();
+    assert!(bump.alloc_concat_slices::<u32>(&[]).is_empty());
+    assert!(bump.alloc_concat_slices::<u32>(&[&[], &[]]).is_empty());
+    let slices: [&[u32]; 4] = [&[1], &[], &[2, 3], &[4]];
+    assert_eq!(bump.alloc_concat_slices(&slices), [1, 2, 3, 4]);
+}
+
+#[test]
+#[should_panic(expected = "fewer items")]
+fn alloc_slice_from_exact_short_iterator() {
+    struct Short;
+
+    impl Iterator for Short {
+        type Item = u32;
+
+        fn next(&mut self) -> Option<u32> {
+            None
+        }
+    }
+
+    impl ExactSizeIterator for Short {
+        fn len(&self) -> usize {
+            4
+        }
+    }
+
+    let bump = Bump::new();
+    bump.alloc_slice_from_exact(Short);
+}
+
+#[test]
+fn alloc_slice_fill_iter_wrong_size_hint() {
+    struct Unknown(u32);
+
+    impl Iterator for Unknown {
+        type Item = u32;
+
+        fn next(&mut self) -> Option<u32> {
+            self.0 = self.0.checked_sub(1)?;
+            Some(self.0)
+        }
+
+        fn size_hint(&self) -> (usize, Option<usize>) {
+            (0, Some(0))
+        }
+    }
+
+    let bump = Bump::new();
+    assert_eq!(bump.alloc_slice_fill_iter(Unknown(3)), [2, 1, 0]);
+}
+
+#[test]
+fn try_reset_all_pending() {
+    let bump = Bump::new();
+    let _ = bump.local().as_inner().alloc(1_u8);
+
+    let (marked_tx, marked_rx) = std::sync::mpsc::channel();
+    let (caught_up_tx, caught_up_rx) = std::sync::mpsc::channel();
+
+    thread::scope(|s| {
+        let bump = &bump;
+        s.spawn(move || {
+            let _ = bump.local().as_inner().alloc(1_u8);
+            caught_up_tx.send(()).unwrap();
+
+            marked_rx.recv().unwrap();
+            assert!(bump.reset_pending());
+            let _ = bump.local();
+            assert!(!bump.reset_pending());
+            caught_up_tx.send(()).unwrap();
+        });
+
+        caught_up_rx.recv().unwrap();
+        // SAFETY: No references to allocated memory are used afterwards.
+        unsafe { bump.try_reset_all() };
+        assert!(!bump.reset_pending());
+        assert_eq!(bump.threads_pending_reset(), 1);
+
+        marked_tx.send(()).unwrap();
+        caught_up_rx.recv().unwrap();
+        assert_eq!(bump.threads_pending_reset(), 0);
+    });
+}
+
+#[test]
+fn alloc_through_clone_blocks_reset() {
+    let mut bump = Bump::new();
+    let clone = bump.clone();
+    let value = clone.alloc(1);
+
+    // The clone can't be dropped while `value` borrows it
+    assert!(bump.reset_all().is_err());
+    *value += 1;
+}
+
+#[test]
+fn clear_all_shrinks_to_base() {
+    let mut bump = Bump::builder().bump_capacity(100).build();
+    let base_capacity = bump.local().as_inner().chunk_capacity();
+
+    let _ = bump.local().as_inner().alloc([0_u8; 4096]);
+    bump.reset_all().unwrap();
+    // reset_all keeps the grown chunk
+    assert!(bump.local().as_inner().chunk_capacity() > base_capacity);
+
+    bump.clear_all().unwrap();
+    assert_eq!(bump.local().as_inner().chunk_capacity(), base_capacity);
+}
+
+#[test]
+fn compact_keeps_allocations() {
+    let mut bump = Bump::builder().bump_capacity(100).build();
+    let base_capacity = bump.local().as_inner().chunk_capacity();
+
+    let _ = bump.local().as_inner().alloc([0_u8; 4096]);
+    bump.compact().unwrap();
+    assert!(bump.reset_all_dry_run().unwrap().allocated_bytes >= 4096);
+
+    bump.reset_all().unwrap();
+    bump.compact().unwrap();
+    assert_eq!(bump.local().as_inner().chunk_capacity(), base_capacity);
+}
+
+#[test]
+fn compact_keeps_overflow_allocations() {
+    let mut bump = Bump::builder()
+        .bump_capacity(64)
+        .bump_allocation_limit(1024)
+        .overflow_capacity(8192)
+        .build();
+
+    // Only the overflow allocator holds an allocation
+    assert!(bump.raw_allocate(Layout::new::<[u8; 4096]>()).is_ok());
+    assert_eq!(bump.reset_all_dry_run().unwrap().allocated_bytes, 0);
+
+    bump.compact().unwrap();
+    assert!(bump.reset_all_dry_run().unwrap().overflow_bytes >= 4096);
+}
+
+#[test]
+fn chunks_allocated_since_reset() {
+    let mut bump = Bump::builder().bump_capacity(100).build();
+    assert_eq!(bump.local().chunks_allocated_since_reset(), 0);
+
+    let _ = bump.local().as_inner().alloc([0_u8; 4096]);
+    assert_eq!(bump.local().chunks_allocated_since_reset(), 1);
+    assert_eq!(bump.chunks_allocated_since_reset().unwrap(), 1);
+
+    bump.reset_all().unwrap();
+    assert_eq!(bump.chunks_allocated_since_reset().unwrap(), 0);
+}
+
+#[test]
+fn spill_vec_spills_and_drops() {
+    let bump = Bump::new();
+    let counter = Arc::new(());
+
+    let mut vec = bump.spill_vec::<Arc<()>, 2>();
+    vec.push(counter.clone());
+    vec.push(counter.clone());
+    assert!(!vec.is_spilled());
+
+    vec.extend((0..10).map(|_| counter.clone()));
+    assert!(vec.is_spilled());
+    assert_eq!(vec.len(), 12);
+    assert_eq!(Arc::strong_count(&counter), 13);
+
+    drop(vec.pop());
+    assert_eq!(Arc::strong_count(&counter), 12);
+
+    drop(vec);
+    assert_eq!(Arc::strong_count(&counter), 1);
+}
+
+#[test]
+fn vec_builder_into_slice() {
+    let bump = Bump::new();
+
+    let empty: &mut [u32] = bump.vec_builder().into_slice();
+    assert!(empty.is_empty());
+
+    let mut builder = ArenaVecBuilder::with_capacity_in(2, &bump);
+    builder.push(1_u32);
+    builder.extend([2, 3]);
+    let slice = builder.into_slice();
+    slice[0] += 10;
+    assert_eq!(slice, [11, 2, 3]);
+
+    let mut spill = bump.spill_vec::<u8, 4>();
+    spill.extend([1, 2]);
+    assert_eq!(spill.into_slice(), [1, 2]);
+}
+
+#[test]
+fn raw_allocate_respects_limit() {
+    let bump = Bump::builder()
+        .bump_capacity(64)
+        .bump_allocation_limit(1024)
+        .build();
+
+    let ptr = bump.raw_allocate(Layout::new::<u64>()).unwrap();
+    assert_eq!(ptr.len(), 8);
+    assert_eq!(ptr.cast::<u8>().as_ptr() as usize % 8, 0);
+
+    assert!(bump.raw_allocate(Layout::new::<[u8; 4096]>()).is_err());
+}
+
+#[test]
+fn at_limit() {
+    let mut bump = Bump::builder().bump_capacity(64).build();
+    assert!(!bump.local().at_limit());
+
+    let mut limited = bump.to_builder().bump_allocation_limit(1024).build();
+    assert!(limited.local().at_limit());
+    assert!(limited.any_at_limit().unwrap());
+    assert!(!bump.any_at_limit().unwrap());
+}
+
+#[cfg(feature = "release_to_os")]
+#[test]
+fn release_to_os_on_reset() {
+    let mut bump = Bump::builder()
+        .bump_capacity(1 << 20)
+        .release_to_os_on_reset(true)
+        .build();
+
+    bump.local()
+        .as_inner()
+        .alloc_slice_fill_copy(1 << 16, 0xff_u8);
+    bump.reset_all().unwrap();
+
+    let data = bump.local().as_inner().alloc_slice_fill_copy(1 << 16, 1_u8);
+    assert!(data.iter().all(|&byte| byte == 1));
+}
+
+#[test]
+fn overflow_capacity() {
+    let mut bump = Bump::builder()
+        .bump_capacity(64)
+        .bump_allocation_limit(1024)
+        .overflow_capacity(8192)
+        .build();
+
+    assert!(bump.raw_allocate(Layout::new::<[u8; 4096]>()).is_ok());
+    assert!(bump.raw_allocate(Layout::new::<[u8; 16384]>()).is_err());
+
+    let stats = bump.reset_all_dry_run().unwrap();
+    assert!(stats.overflow_bytes >= 4096);
+    assert!(stats.allocated_bytes < 1024);
+
+    bump.reset_all().unwrap();
+    assert_eq!(bump.reset_all_dry_run().unwrap().overflow_bytes, 0);
+}
+
+#[test]
+fn reset_all_reporting_peak() {
+    let mut bump = Bump::builder().bump_capacity(100).build();
+    let _ = bump.alloc_bytes_zeroed(64);
+    assert!(bump.local().peak_allocated_bytes() >= 64);
+
+    let stats = bump.reset_all_reporting().unwrap();
+    assert!(stats.peak_bytes >= 64);
+    assert_eq!(bump.local().peak_allocated_bytes(), 0);
+}
+
+#[test]
+fn send_ref_across_threads() {
+    let bump = Bump::new();
+    let sent = std::thread::scope(|s| {
+        // SAFETY: The spawned thread doesn't reset its allocator.
+        s.spawn(|| unsafe { bump.alloc_send([1, 2, 3]) })
+            .join()
+            .unwrap()
+    });
+    assert_eq!(sent.into_ref(&bump), &[1, 2, 3]);
+}
+
+#[test]
+#[should_panic(expected = "reset after")]
+fn send_ref_after_reset_panics() {
+    let mut bump = Bump::new();
+    // SAFETY: The allocator isn't reset through `BumpLocal`.
+    let sent = unsafe { bump.alloc_send(1) };
+    bump.reset_all().unwrap();
+    sent.into_ref(&bump);
+}
+
+#[test]
+#[should_panic(expected = "another Bump")]
+fn send_ref_other_bump_panics() {
+    let bump = Bump::new();
+    // SAFETY: The allocator isn't reset through `BumpLocal`.
+    let sent = unsafe { bump.alloc_send(1) };
+    sent.into_ref(&Bump::new());
+}
+
+#[test]
+fn allocated_chunk_ranges() {
+    let bump = Bump::builder().bump_capacity(64).build();
+    let values: Vec<*const u8> = (0..64)
+        .map(|_| bump.alloc_bytes_zeroed(32).as_ptr())
+        .collect();
+
+    let ranges: Vec<_> = bump.local().allocated_chunk_ranges().collect();
+    assert!(ranges.len() > 1);
+    assert_eq!(ranges.iter().map(|(_, len)| len).sum::<usize>(), 64 * 32);
+    for value in values {
+        assert!(
+            ranges
+                .iter()
+                .any(|&(start, len)| start <= value && value < start.wrapping_add(len))
+        );
+    }
+}
+
+#[test]
+fn on_before_reset_order() {
+    let mut bump = Bump::new();
+    let (tx, rx) = std::sync::mpsc::channel();
+    for i in 0..3 {
+        let tx = tx.clone();
+        bump.on_before_reset(move || tx.send(i).unwrap());
+    }
+
+    let clone = bump.clone();
+    assert!(bump.reset_all().is_err());
+    assert!(rx.try_recv().is_err());
+    drop(clone);
+
+    bump.reset_all().unwrap();
+    assert_eq!(rx.try_iter().collect::<Vec<_>>(), [0, 1, 2]);
+}
+
+#[cfg(feature = "timing")]
+#[test]
+fn reset_all_reporting_durations() {
+    let mut bump = Bump::new();
+    let _ = bump.alloc(1_u8);
+    std::thread::scope(|s| {
+        s.spawn(|| bump.alloc(2_u8));
+    });
+
+    let stats = bump.reset_all_reporting().unwrap();
+    assert_eq!(stats.per_thread_durations.len(), 2);
+    assert!(
+        stats
+            .per_thread_durations
+            .iter()
+            .any(|(id, _)| *id == std::thread::current().id())
+    );
+    assert!(
+        bump.reset_all_dry_run()
+            .unwrap()
+            .per_thread_durations
+            .is_empty()
+    );
+}
+
+#[test]
+fn alloc_layout_excess() {
+    let bump = Bump::builder().bump_capacity(64).build();
+    let _ = bump.alloc(1_u8);
+
+    let layout = Layout::from_size_align(5, 8).unwrap();
+    let (first, usable) = bump.alloc_layout_excess(layout);
+    assert_eq!(first.as_ptr() as usize % 8, 0);
+    // The size is rounded up to the alignment, plus the padding to align the pointer.
+    assert!((8..8 + 8).contains(&usable));
+
+    // The next allocation ends where the previous one's usable bytes start.
+    let (second, usable) = bump.alloc_layout_excess(layout);
+    assert!(usable >= 5);
+    assert_eq!(second.as_ptr() as usize + usable, first.as_ptr() as usize);
+}
+
+#[test]
+fn prewarm_current() {
+    let bump = Bump::builder().bump_capacity(64 << 10).build();
+    std::thread::scope(|s| {
+        s.spawn(|| {
+            let local = bump.prewarm_current();
+            assert!(std::ptr::eq(local, bump.local()));
+            assert!(local.as_inner().chunk_capacity() >= 64 << 10);
+            assert_eq!(bump.alloc_slice_fill_default::<u8>(1024), [0; 1024]);
+        });
+    });
+}
+
+#[test]
+#[should_panic(expected = "bump capacity overflow")]
+fn bump_capacity_for_overflow_panics() {
+    let _ = Bump::builder().bump_capacity_for::<u64>(usize::MAX / 4);
+}
+
+#[cfg(feature = "hashbrown")]
+#[test]
+fn hashmap() {
+    let mut bump = Bump::new();
+    let mut map = bump.hashmap();
+    map.extend((0..1000).map(|i| (i, i * 2)));
+    assert_eq!(map.len(), 1000);
+    assert_eq!(map[&500], 1000);
+    drop(map);
+
+    assert!(bump.reset_all_dry_run().unwrap().allocated_bytes > 0);
+    bump.reset_all().unwrap();
+}
+
+#[test]
+fn alloc_cstr() {
+    let bump = Bump::new();
+    assert_eq!(bump.alloc_cstr("").unwrap().to_bytes_with_nul(), b"\0");
+    assert_eq!(bump.alloc_cstr("ß").unwrap().to_str(), Ok("ß"));
+
+    let allocated = bump.local().peak_allocated_bytes();
+    let err = bump.alloc_cstr("\0").unwrap_err();
+    assert_eq!(err.nul_position(), 0);
+    assert_eq!(bump.local().peak_allocated_bytes(), allocated);
+}
+
+#[test]
+fn scoped_returns_result() {
+    let bump = Bump::new();
+    let weak = bump.downgrade();
+    let len = bump.scoped(|bump| bump.alloc_str_concat(&["ab", "c"]).len());
+    assert_eq!(len, 3);
+    assert!(weak.upgrade().is_none());
+}
+
+#[test]
+fn on_chunk_grow() {
+    let sizes = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+    let mut bump = Bump::builder()
+        .bump_capacity(64)
+        .on_chunk_grow({
+            let sizes = sizes.clone();
+            move |local, size| {
+                assert!(local.as_inner().chunk_capacity() < size);
+                sizes.lock().unwrap().push(size);
+            }
+        })
+        .build();
+
+    let _ = bump.alloc(1_u8);
+    assert!(sizes.lock().unwrap().is_empty());
+
+    let _ = bump.alloc_bytes(1024);
+    let _ = bump.alloc_bytes(1024);
+    let grown = sizes.lock().unwrap().clone();
+    assert!(!grown.is_empty());
+    assert!(grown[0] >= 1024);
+
+    // Resetting keeps the largest chunk, which fits the same allocations again.
+    bump.reset_all().unwrap();
+    let _ = bump.alloc_bytes(1024);
+    assert_eq!(*sizes.lock().unwrap(), grown);
+}
+
+#[test]
+fn zero_length_slices() {
+    let bump = Bump::builder().min_alignment(64).build();
+
+    let empty: &mut [u64] = bump.copy_into(&[]);
+    assert!(empty.is_empty());
+    assert_eq!(empty.as_ptr() as usize % std::mem::align_of::<u64>(), 0);
+    assert_eq!(bump.alloc_str_concat(&["", ""]), "");
+    assert!(bump.alloc_bytes(0).is_empty());
+    assert!(bump.alloc_slice_fill_default::<u32>(0).is_empty());
+    assert_eq!(bump.alloc_slice_fill_default::<()>(3).len(), 3);
+
+    assert_eq!(bump.local().peak_allocated_bytes(), 0);
+}
+
+#[test]
+fn memory_report() {
+    let mut bump = Bump::builder().bump_capacity(1 << 20).build();
+    assert_eq!(
+        bump.memory_report().unwrap(),
+        "0 threads, 0 B allocated / 0 B reserved (0% util), 0 resets"
+    );
+
+    let _ = bump.alloc_bytes(1536);
+    let report = bump.memory_report().unwrap();
+    assert!(report.starts_with("1 thread, 1.5 KiB allocated / 1.0 MiB reserved"));
+
+    bump.reset_all().unwrap();
+    assert!(bump.memory_report().unwrap().ends_with(", 1 reset"));
+    bump.clear_all().unwrap();
+    assert!(bump.memory_report().unwrap().ends_with(", 2 resets"));
+
+    let clone = bump.clone();
+    assert!(bump.memory_report().is_err());
+    drop(clone);
+}
+
+#[test]
+fn threads_allocate_in_disjoint_chunks() {
+    let bump = Bump::builder().bump_capacity(256).build();
+    // Both threads stay alive until both are done, so neither can take over the other's slot.
+    let barrier = std::sync::Barrier::new(2);
+
+    let [first, second] = std::thread::scope(|s| {
+        let handles = [0, 1].map(|_| {
+            s.spawn(|| {
+                let values: Vec<usize> = (0..100)
+                    .map(|i| bump.alloc([i; 8]).as_ptr() as usize)
+                    .collect();
+                let ranges: Vec<(usize, usize)> = bump
+                    .local()
+                    .allocated_chunk_ranges()
+                    .map(|(start, len)| (start as usize, start as usize + len))
+                    .collect();
+                barrier.wait();
+
+                for value in values {
+                    assert!(
+                        ranges
+                            .iter()
+                            .any(|&(start, end)| (start..end).contains(&value))
+                    );
+                }
+                ranges
+            })
+        });
+        handles.map(|handle| handle.join().unwrap())
+    });
+
+    for &(start, end) in &first {
+        for &(other_start, other_end) in &second {
+            assert!(end <= other_start || other_end <= start);
+        }
+    }
+}
+
+#[test]
+fn alloc_slice_fill_copy() {
+    let bump = Bump::new();
+    assert_eq!(bump.alloc_slice_fill_copy(3, true), [true; 3]);
+    assert_eq!(bump.alloc_slice_fill_copy(3, -1_i8), [-1; 3]);
+    assert_eq!(bump.alloc_slice_fill_copy(3, 7_u32), [7; 3]);
+    assert!(bump.alloc_slice_fill_copy(0, 1_u8).is_empty());
+    // One-byte values don't have to be initialized
+    let uninit = bump.alloc_slice_fill_copy(3, std::mem::MaybeUninit::<u8>::uninit());
+    assert_eq!(uninit.len(), 3);
+}
+
+#[test]
+fn try_local() {
+    let bump = Bump::builder()
+        .bump_capacity(4096)
+        .bump_allocation_limit(1024)
+        .build();
+    assert!(bump.try_local().is_err());
+    assert!(bump.try_local().is_err());
+
+    let bump = Bump::builder()
+        .bump_capacity(1024)
+        .bump_allocation_limit(4096)
+        .build();
+    let local = bump.try_local().unwrap();
+    assert!(std::ptr::eq(local, bump.local()));
+}
+
+#[test]
+fn thread_ids() {
+    let mut bump = Bump::new();
+    bump.local();
+
+    let handle = {
+        let bump = bump.clone();
+        thread::spawn(move || {
+            bump.local();
+            thread::current().id()
+        })
+    };
+    let exited = handle.join().unwrap();
+
+    let mut ids = bump.thread_ids().unwrap();
+    ids.sort_by_key(|&(_, alive)| alive);
+    assert_eq!(ids, [(exited, false), (thread::current().id(), true)]);
+
+    bump.reset_all().unwrap();
+    assert_eq!(bump.thread_ids().unwrap(), [(thread::current().id(), true)]);
+
+    let _clone = bump.clone();
+    assert!(bump.thread_ids().is_err());
+}
+
+#[test]
+#[cfg(debug_assertions)]
+fn poison_on_reset() {
+    let mut bump = Bump::builder().poison_on_reset().build();
+    bump.alloc_slice_fill_copy(16, 1_u8);
+    bump.reset_all().unwrap();
+
+    // The reset allocator hands out the same memory again
+    let bytes = bump.alloc_bytes(16);
+    assert!(
+        bytes
+            .iter()
+            .all(|byte| unsafe { byte.assume_init() } == 0xDE)
+    );
+}
+
+#[test]
+fn reset_all_from_thread_without_allocator() {
+    let mut bump = Bump::builder().retain_dead_arenas(true).build();
+
+    let handle = {
+        let bump = bump.clone();
+        thread::spawn(move || {
+            let _ = bump.alloc(1_u64);
+            thread::current().id()
+        })
+    };
+    let worker = handle.join().unwrap();
+
+    bump.reset_all().unwrap();
+    assert_eq!(bump.thread_ids().unwrap(), [(worker, false)]);
+}
+
+#[test]
+fn lazy_reset_mode() {
+    for mode in [LazyResetMode::Reset, LazyResetMode::Reinit] {
+        let bump = Bump::builder()
+            .bump_capacity(64)
+            .lazy_reset_mode(mode)
+            .build();
+        let initial = bump.local().as_inner().allocated_bytes();
+        bump.alloc_bytes_zeroed(4096);
+        let grown = bump.local().as_inner().allocated_bytes();
+
+        // SAFETY: No allocated memory is used after the reset.
+        unsafe { bump.try_reset_all() };
+        let bytes = bump.local().as_inner().allocated_bytes();
+        assert_eq!(bump.local().peak_allocated_bytes(), 0);
+        match mode {
+            LazyResetMode::Reset => assert!(bytes > initial && bytes < grown),
+            LazyResetMode::Reinit => assert_eq!(bytes, initial),
+        }
+    }
+}
+
+#[test]
+fn over_aligned_slices() {
+    #[derive(Clone, Copy, Default, PartialEq, Debug)]
+    #[repr(align(64))]
+    struct Line(u8);
+
+    #[derive(Clone, Copy, Default)]
+    #[repr(align(64))]
+    struct Empty;
+
+    fn assert_aligned<T>(slice: &[T]) {
+        for item in slice {
+            assert_eq!(item as *const T as usize % 64, 0);
+        }
+    }
+
+    let bump = Bump::new();
+    let src = [Line(1), Line(2), Line(3)];
+
+    let _ = bump.alloc(1_u8);
+    let copy = bump.copy_into(&src);
+    assert_eq!(copy, src);
+    assert_aligned(copy);
+
+    let _ = bump.alloc(1_u8);
+    assert_aligned(bump.alloc_slice_fill_copy(3, Line(4)));
+    let _ = bump.alloc(1_u8);
+    assert_aligned(bump.alloc_slice_fill_default::<Line>(3));
+    let _ = bump.alloc(1_u8);
+    assert_aligned(bump.alloc_slice_from_exact(src));
+    let _ = bump.alloc(1_u8);
+    assert_aligned(bump.alloc_concat_slices(&[&src[..1], &src]));
+    assert_aligned(bump.alloc_slice_fill_copy(3, Empty));
+    let _ = bump.alloc(1_u8);
+    assert_aligned(bump.alloc_array_uninit::<Line, 3>());
+    let _ = bump.alloc(1_u8);
+    assert_aligned(bump.alloc_array_with::<Line, 3>(|i| src[i]));
+}
+
+#[test]
+fn alloc_array() {
+    let bump = Bump::new();
+    let array = bump.alloc_array_uninit::<u64, 3>();
+    assert_eq!(array.len(), 3);
+    assert!(bump.alloc_array_uninit::<u64, 0>().is_empty());
+
+    assert_eq!(bump.alloc_array_with::<_, 3>(|i| i * 10), &[0, 10, 20]);
+    assert_eq!(bump.alloc_array_with::<u8, 0>(|_| unreachable!()), &[]);
+}
+
+#[test]
+fn into_chunks() {
+    let bump = Bump::new();
+    bump.alloc_slice_fill_copy(16, 0xAB_u8);
+    thread::scope(|s| {
+        s.spawn(|| {
+            bump.alloc_slice_fill_copy(8, 0xCD_u8);
+        });
+    });
+
+    // The clone isn't the only handle
+    assert!(bump.clone().into_chunks().is_err());
+
+    let mut chunks: Vec<Vec<u8>> = bump
+        .into_chunks()
+        .unwrap()
+        // SAFETY: Every allocation initialized all of its bytes.
+        .map(|chunk| {
+            chunk
+                .iter()
+                .map(|byte| unsafe { byte.assume_init() })
+                .collect()
+        })
+        .collect();
+    chunks.sort();
+    assert_eq!(chunks, [vec![0xAB; 16], vec![0xCD; 8]]);
+}
+
+#[test]
+fn reset_to_chunk_size() {
+    const SIZE: usize = 64 * 1024;
+    let chunk_bytes = Bump::builder()
+        .bump_capacity(SIZE)
+        .build()
+        .local()
+        .as_inner()
+        .allocated_bytes();
+
+    let mut bump = Bump::builder()
+        .retain_dead_arenas(true)
+        .reset_to_chunk_size(SIZE)
+        .build();
+    // Both threads stay alive until both allocated, so they don't share an allocator
+    let barrier = std::sync::Barrier::new(2);
+    thread::scope(|s| {
+        s.spawn(|| {
+            bump.alloc_bytes(1 << 20);
+            barrier.wait();
+        });
+        s.spawn(|| {
+            bump.alloc(1_u8);
+            barrier.wait();
+        });
+    });
+
+    for _ in 0..2 {
+        bump.reset_all().unwrap();
+        let stats = bump.reset_all_dry_run().unwrap();
+        assert_eq!(stats.threads_reset, 2);
+        assert_eq!(stats.capacity_bytes, 2 * chunk_bytes);
+    }
+}
+
+#[test]
+fn rebalance_on_reset() {
+    const BIG: usize = 256 * 1024;
+    const SMALL: usize = 64;
+
+    let mut bump = Bump::builder()
+        .bump_capacity(64 * 1024)
+        .rebalance_on_reset(true)
+        .build();
+    // Each worker allocates the size it receives and reports its chunk bytes.
+    // The workers live through all cycles, so they keep their allocators.
+    let (report_tx, report_rx) = std::sync::mpsc::channel();
+    let workers: Vec<_> = (0..2)
+        .map(|worker| {
+            let (work_tx, work_rx) = std::sync::mpsc::channel::<usize>();
+            let weak = bump.downgrade();
+            let report_tx = report_tx.clone();
+            let handle = thread::spawn(move || {
+                for size in work_rx {
+                    let bump = weak.upgrade().unwrap();
+                    bump.alloc_bytes(size);
+                    let bytes = bump.local().as_inner().allocated_bytes();
+                    drop(bump);
+                    report_tx.send((worker, bytes)).unwrap();
+                }
+            });
+            (work_tx, handle)
+        })
+        .collect();
+
+    let mut history = [Vec::new(), Vec::new()];
+    for cycle in 0..6 {
+        // The workloads swap halfway, so both workers have to grow and shrink
+        let sizes = if cycle < 3 {
+            [BIG, SMALL]
+        } else {
+            [SMALL, BIG]
+        };
+        for (worker, size) in sizes.into_iter().enumerate() {
+            workers[worker].0.send(size).unwrap();
+        }
+        for _ in 0..2 {
+            let (worker, bytes) = report_rx.recv().unwrap();
+            history[worker].push(bytes);
+        }
+        bump.reset_all().unwrap();
+    }
+    for (work_tx, handle) in workers {
+        drop(work_tx);
+        handle.join().unwrap();
+    }
+
+    // After one cycle of a workload, each chunk fits it without growing
+    for (worker, bytes) in history.iter().enumerate() {
+        let (first, second) = (&bytes[1..3], &bytes[4..6]);
+        let (big, small) = if worker == 0 {
+            (first, second)
+        } else {
+            (second, first)
+        };
+        assert_eq!(big[0], big[1]);
+        assert!(big[0] >= BIG && big[0] <= 4 * BIG);
+        assert_eq!(small[0], small[1]);
+        assert!(small[0] <= 4096);
+    }
+}
+
+#[test]
+fn alloc_array_with_panic_drops_prefix() {
+    let bump = Bump::new();
+    let owner = Arc::new(());
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        bump.alloc_array_with::<_, 8>(|i| {
+            assert!(i < 3, "fill failed");
+            Arc::clone(&owner)
+        });
+    }));
+    assert!(result.is_err());
+    assert_eq!(Arc::strong_count(&owner), 1);
+}
+
+#[test]
+fn alloc_slice_move() {
+    let bump = Bump::new();
+    let owner = Arc::new(());
+    let moved = bump.alloc_slice_move(vec![Arc::clone(&owner), Arc::clone(&owner)]);
+    assert_eq!(Arc::strong_count(&owner), 3);
+
+    // SAFETY: The items aren't used after being dropped.
+    unsafe { std::ptr::drop_in_place(moved) };
+    assert_eq!(Arc::strong_count(&owner), 1);
+}
+
+#[test]
+fn base_capacity() {
+    let mut bump = Bump::builder().bump_capacity(64).build();
+    bump.alloc_bytes(1024);
+    assert_eq!(bump.local().base_capacity(), 64);
+
+    bump.reconfigure(128, None);
+    bump.reset_all().unwrap();
+    assert_eq!(bump.local().base_capacity(), 64);
+    bump.clear_all().unwrap();
+    assert_eq!(bump.local().base_capacity(), 128);
+}
+
+#[test]
+#[should_panic(expected = "invalid layout")]
+fn alloc_raw_invalid_align_panics() {
+    Bump::new().alloc_raw(8, 3);
+}
+
+#[test]
+fn prefault() {
+    let mut bump = Bump::builder()
+        .bump_capacity(64 * 1024)
+        .prefault(true)
+        .build();
+    assert!(bump.local().as_inner().chunk_capacity() >= 64 * 1024);
+    assert_eq!(bump.local().peak_allocated_bytes(), 0);
+
+    bump.clear_all().unwrap();
+    assert_eq!(bump.reset_all_dry_run().unwrap().allocated_bytes, 0);
+}
+
+#[cfg(feature = "interner")]
+#[test]
+fn interner() {
+    use bump_local::Interner;
+
+    let mut interner = Interner::new();
+    let a = interner.intern("key");
+    assert!(std::ptr::eq(a, interner.intern(&String::from("key"))));
+    assert!(!std::ptr::eq(a, interner.intern("other")));
+    assert_eq!(interner.len(), 2);
+
+    let other = thread::scope(|s| s.spawn(|| interner.intern("key")).join().unwrap());
+    assert_eq!(a, other);
+    assert!(!std::ptr::eq(a, other));
+
+    interner.reset().unwrap();
+    assert!(interner.is_empty());
+    assert_eq!(interner.intern("key"), "key");
+
+    let bump = Bump::new();
+    let mut interner = Interner::with_bump(bump.clone());
+    interner.intern("key");
+    assert!(interner.reset().is_err());
+    assert_eq!(interner.len(), 1);
+
+    // A panicking reset doesn't leave strings pointing into reset memory
+    let bump = Bump::builder()
+        .metrics_sink(|_| panic!("sink failed"))
+        .build();
+    let mut interner = Interner::with_bump(bump);
+    interner.intern("key");
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| interner.reset()));
+    assert!(result.is_err());
+    assert!(interner.is_empty());
+}
+
+#[test]
+fn arena_rc() {
+    use std::cell::RefCell;
+
+    use bump_local::ArenaRc;
+
+    struct Node<'a> {
+        owner: Arc<()>,
+        next: RefCell<Option<ArenaRc<'a, Node<'a>>>>,
+    }
+
+    let mut bump = Bump::new();
+    let owner = Arc::new(());
+    {
+        let first = bump.alloc_rc(Node {
+            owner: Arc::clone(&owner),
+            next: RefCell::new(None),
+        });
+        let second = bump.alloc_rc(Node {
+            owner: Arc::clone(&owner),
+            next: RefCell::new(Some(ArenaRc::clone(&first))),
+        });
+        assert_eq!(ArenaRc::strong_count(&first), 2);
+        assert!(ArenaRc::ptr_eq(
+            &second.next.borrow().clone().unwrap(),
+            &first
+        ));
+        assert_eq!(Arc::strong_count(&second.owner), 3);
+
+        drop(first);
+        assert_eq!(Arc::strong_count(&owner), 3);
+    }
+    // Dropping the last clones dropped both nodes
+    assert_eq!(Arc::strong_count(&owner), 1);
+    bump.reset_all().unwrap();
+}
+
+#[test]
+fn metrics_sink() {
+    let reports = Arc::new(Mutex::new(Vec::new()));
+    let mut bump = Bump::builder()
+        .metrics_sink({
+            let reports = reports.clone();
+            move |stats| reports.lock().unwrap().push(stats.clone())
+        })
+        .build();
+
+    bump.alloc_bytes_zeroed(64);
+    bump.reset_all().unwrap();
+    bump.alloc_bytes_zeroed(32);
+    let stats = bump.reset_all_reporting().unwrap();
+
+    let clone = bump.clone();
+    assert!(bump.reset_all().is_err());
+    drop(clone);
+
+    let reports = reports.lock().unwrap();
+    assert_eq!(reports.len(), 2);
+    assert!(reports[0].allocated_bytes >= 64);
+    assert_eq!(reports[1], stats);
+}
+
+#[test]
+fn panic_on_growth() {
+    let bump = Bump::builder().bump_capacity(64).panic_on_growth().build();
+    bump.alloc_bytes(32);
+
+    let result = std::panic::catch_unwind(|| {
+        bump.alloc_bytes(1024);
+    });
+    let message = *result.unwrap_err().downcast::<String>().unwrap();
+    assert!(message.starts_with("bump allocator grew by"));
+}
+
+#[test]
+fn unwind_safe() {
+    fn check<T: std::panic::UnwindSafe + std::panic::RefUnwindSafe>() {}
+    check::<Bump>();
+    check::<bump_local::BumpBuilder>();
+}
+
+#[test]
+fn checkpoint_restore() {
+    let mut bump = Bump::builder().bump_capacity(1024).build();
+    bump.alloc_slice_copy_raw(&[1_u8, 2, 3]);
+    let checkpoint = bump.checkpoint_all().unwrap();
+    assert_eq!(checkpoint.thread_count(), 1);
+    assert_eq!(checkpoint.bytes(), 3);
+    let (start, _) = bump.local().allocated_chunk_ranges().next().unwrap();
+
+    for _ in 0..2 {
+        bump.alloc_slice_copy_raw(&[4_u8; 16]);
+        bump.restore_all(&checkpoint).unwrap();
+        let (restored, len) = bump.local().allocated_chunk_ranges().next().unwrap();
+        assert_eq!((restored, len), (start, 3));
+        // SAFETY: The restored range holds the three initialized bytes.
+        assert_eq!(
+            unsafe { std::slice::from_raw_parts(restored, len) },
+            [1, 2, 3]
+        );
+    }
+
+    // Restoring an empty snapshot resets
+    let empty = Bump::new().checkpoint_all().unwrap();
+    bump.restore_all(&empty).unwrap();
+    assert_eq!(bump.local().peak_allocated_bytes(), 0);
+}
+
+#[test]
+fn checkpoint_restore_errors() {
+    let mut bump = Bump::builder().bump_capacity(64).build();
+    bump.alloc(1_u8);
+    let checkpoint = bump.checkpoint_all().unwrap();
+
+    // Growing replaces the chunk the checkpoint was taken from
+    bump.alloc_bytes(4096);
+    assert_eq!(
+        bump.checkpoint_all().unwrap_err(),
+        SnapshotError::Fragmented
+    );
+    assert_eq!(
+        bump.restore_all(&checkpoint).unwrap_err(),
+        SnapshotError::ChunkReplaced
+    );
+    assert!(bump.local().chunks_allocated_since_reset() > 0);
+
+    let clone = bump.clone();
+    assert_eq!(bump.checkpoint_all().unwrap_err(), SnapshotError::Shared);
+    drop(clone);
+}
+
+#[test]
+fn local_from_thread_local_destructor() {
+    use std::{cell::RefCell, sync::mpsc};
+
+    // Runs its closure when the thread's thread-locals are destroyed.
+    struct OnExit(Option<Box<dyn FnOnce()>>);
+
+    impl Drop for OnExit {
+        fn drop(&mut self) {
+            if let Some(f) = self.0.take() {
+                f();
+            }
+        }
+    }
+
+    thread_local! {
+        static ON_EXIT: RefCell<OnExit> = const { RefCell::new(OnExit(None)) };
+    }
+
+    let bump = Bump::new();
+    let (spawn_tx, spawn_rx) = mpsc::channel();
+    let (other_tx, other_rx) = mpsc::channel::<usize>();
+    let (result_tx, result_rx) = mpsc::channel();
+
+    let exiting = {
+        let bump = bump.clone();
+        thread::spawn(move || {
+            // Registered before the allocator's thread-locals, so it is destroyed after them
+            let exit_bump = bump.clone();
+            ON_EXIT.with(|on_exit| {
+                on_exit.borrow_mut().0 = Some(Box::new(move || {
+                    // Another thread may take over this thread's slot now
+                    spawn_tx.send(()).unwrap();
+                    let other = other_rx.recv().unwrap();
+                    // The `thread_local` crate panics once it released the thread's entry
+                    let local = std::panic::catch_unwind(|| exit_bump.local() as *const _ as usize);
+                    result_tx.send((local, other)).unwrap();
+                }));
+            });
+            bump.alloc(1_u8);
+        })
+    };
+
+    spawn_rx.recv().unwrap();
+    let (release_tx, release_rx) = mpsc::channel::<()>();
+    let other = {
+        let bump = bump.clone();
+        thread::spawn(move || {
+            other_tx.send(bump.local() as *const _ as usize).unwrap();
+            // Stays alive, so its slot can't be handed back
+            let _ = release_rx.recv();
+        })
+    };
+
+    let (local, other_local) = result_rx.recv().unwrap();
+    assert_ne!(local.ok(), Some(other_local));
+    drop(release_tx);
+    exiting.join().unwrap();
+    other.join().unwrap();
+}
@@ -0,0 +1,11 @@
+use bump_local::Bump;
+
+fn main() {
+    let mut bump = Bump::new();
+    let clone = bump.clone();
+    let value = clone.local().as_inner().alloc(1);
+    // The clone must be dropped for `reset_all` to succeed, but it's still borrowed.
+    drop(clone);
+    bump.reset_all().unwrap();
+    *value += 1;
+}
@@ -0,0 +1,8 @@
+use bump_local::Bump;
+
+fn main() {
+    let mut bump = Bump::new();
+    let value = bump.alloc(1);
+    bump.reset_all().unwrap();
+    *value += 1;
+}
@@ -0,0 +1,7 @@
+use bump_local::Bump;
+
+fn main() {
+    let bump = Bump::new();
+    let value = bump.with_local(|arena| arena.alloc(1));
+    *value += 1;
+}
@@ -0,0 +1,8 @@
+// Allocations borrow the `Bump` handle they were made through,
+// so they can't be used across `reset_all` or escape `with_local`.
+#[test]
+#[cfg_attr(miri, ignore)]
+fn compile_fail() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/compile-fail/*.rs");
+}
@@ -0,0 +1,54 @@
+//! Model checks of the liveness flag and exclusive access protocols.
+//!
+//! Run with `RUSTFLAGS="--cfg loom" cargo test --release --test loom`.
+#![cfg(loom)]
+
+use bump_local::Bump;
+use loom::thread;
+
+#[test]
+fn reset_concurrent_with_thread_exit() {
+    loom::model(|| {
+        let mut bump = Bump::builder().bump_capacity(64).build();
+
+        let handle = {
+            let bump = bump.clone();
+            thread::spawn(move || {
+                let _ = bump.local().as_inner().alloc(1_u8);
+            })
+        };
+
+        // The worker's handle is dropped before its liveness flag is cleared,
+        // so the reset races with the thread exit.
+        while bump.reset_all().is_err() {
+            thread::yield_now();
+        }
+
+        let stats = bump.reset_all_dry_run().unwrap();
+        assert_eq!(stats.allocated_bytes, 0);
+        assert!(stats.threads_reset + stats.threads_dropped <= 1);
+
+        handle.join().unwrap();
+    });
+}
+
+#[test]
+fn upgrade_concurrent_with_reset() {
+    loom::model(|| {
+        let mut bump = Bump::builder().bump_capacity(64).build();
+        let weak = bump.downgrade();
+
+        let handle = thread::spawn(move || {
+            if let Some(bump) = weak.upgrade() {
+                let _ = bump.local().as_inner().alloc(1_u8);
+            }
+        });
+
+        // Either the upgrade fails, or the reset waits for the upgraded handle.
+        while bump.reset_all().is_err() {
+            thread::yield_now();
+        }
+
+        handle.join().unwrap();
+    });
+}